@@ -1,4 +1,7 @@
-use std::ops::{Bound, RangeBounds};
+use std::ops::RangeBounds;
+
+use range_bounds::{to_coord_range, to_range};
+use semiring::Ring;
 
 use magma;
 use monoid;
@@ -56,6 +59,59 @@ where
             index += index & !(index - 1);
         }
     }
+
+    /// return the maximum r such that `f(sum(r))` holds.
+    /// `f` must be monotone on the prefix sums and `f(identity)` must be true.
+    pub fn max_right<F>(&self, f: F) -> usize
+    where
+        F: Fn(&<T as magma::Magma>::S) -> bool,
+    {
+        assert!(f(&T::identity()));
+        let mut pos = 0;
+        let mut acc = T::identity();
+        let mut step = if self.size == 0 {
+            0
+        } else {
+            1 << self.size.ilog2()
+        };
+        while step > 0 {
+            if pos + step <= self.size {
+                let nxt = T::binary_operation(acc.clone(), self.data[pos + step].clone());
+                if f(&nxt) {
+                    pos += step;
+                    acc = nxt;
+                }
+            }
+            step >>= 1;
+        }
+        pos
+    }
+
+    /// return the minimum index i such that `sum(i + 1) >= value`, or n if there is none.
+    /// the prefix sums must be non-decreasing.
+    pub fn lower_bound(&self, value: &<T as magma::Magma>::S) -> usize
+    where
+        <T as magma::Magma>::S: Ord,
+    {
+        if T::identity() >= *value {
+            return 0;
+        }
+        self.max_right(|x| x < value)
+    }
+
+    /// for a tree counting the occurrences of each index, return the k-th (0-indexed)
+    /// smallest element of the multiset, or `None` if it has at most k elements.
+    pub fn kth(&self, k: &<T as magma::Magma>::S) -> Option<usize>
+    where
+        <T as magma::Magma>::S: Ord,
+    {
+        let i = self.max_right(|x| x <= k);
+        if i < self.size {
+            Some(i)
+        } else {
+            None
+        }
+    }
 }
 
 impl<T> BinaryIndexedTree<T>
@@ -67,29 +123,332 @@ where
     where
         R: RangeBounds<usize>,
     {
-        let upper = match range.end_bound() {
-            Bound::Included(x) => *x + 1,
-            Bound::Excluded(x) => *x,
-            Bound::Unbounded => self.size,
-        };
-        let lower = match range.start_bound() {
-            Bound::Included(x) => *x,
-            Bound::Excluded(x) => *x + 1,
-            Bound::Unbounded => 0,
-        };
-        assert!(upper <= self.size);
-        assert!(lower < self.size);
+        let (lower, upper) = to_range(range, self.size);
         T::binary_operation(self.sum(upper), T::inverse(self.sum(lower)))
     }
+
+    pub fn get(&self, index: usize) -> <T as magma::Magma>::S {
+        self.range_sum(index..=index)
+    }
+
+    pub fn set(&mut self, index: usize, val: <T as magma::Magma>::S) {
+        let delta = T::binary_operation(val, T::inverse(self.get(index)));
+        self.add(index, &delta);
+    }
+}
+
+/// n * one() by doubling, since a ring has no conversion from integers.
+fn from_usize<R: Ring>(n: usize) -> R::S {
+    let mut res = R::zero();
+    let mut x = R::one();
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            res = R::add(res, x.clone());
+        }
+        x = R::add(x.clone(), x);
+        n >>= 1;
+    }
+    res
+}
+
+/// # Range Add Range Sum BIT
+/// range add and range sum over a ring with two BITs.
+/// the i-th prefix sum is kept as `linear.sum(i) * i + constant.sum(i)`.
+/// ```
+/// use binary_indexed_tree::RangeAddRangeSumBit;
+/// use semiring::AddMul;
+///
+/// let mut bit = RangeAddRangeSumBit::<AddMul<i64>>::from_vec(&[3, 1, 4, 1, 5]);
+/// bit.range_add(1..4, 10);
+/// assert_eq!(bit.range_sum(..), 44);
+/// assert_eq!(bit.range_sum(3..=4), 16);
+/// ```
+pub struct RangeAddRangeSumBit<R: Ring> {
+    linear: BinaryIndexedTree<R::Additive>,
+    constant: BinaryIndexedTree<R::Additive>,
+    size: usize,
+}
+
+impl<R: Ring> RangeAddRangeSumBit<R> {
+    pub fn new(n: usize) -> Self {
+        RangeAddRangeSumBit {
+            linear: BinaryIndexedTree::new(n),
+            constant: BinaryIndexedTree::new(n),
+            size: n,
+        }
+    }
+
+    pub fn from_vec(v: &[R::S]) -> Self {
+        RangeAddRangeSumBit {
+            linear: BinaryIndexedTree::new(v.len()),
+            constant: BinaryIndexedTree::from_vec(&v.to_vec()),
+            size: v.len(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// add `val` to every element in `range`.
+    pub fn range_add<B>(&mut self, range: B, val: R::S)
+    where
+        B: RangeBounds<usize>,
+    {
+        let (l, r) = to_range(range, self.size);
+        if l == r {
+            return;
+        }
+        self.linear.add(l, &val);
+        self.constant
+            .add(l, &R::neg(R::mul(val.clone(), from_usize::<R>(l))));
+        if r < self.size {
+            self.linear.add(r, &R::neg(val.clone()));
+            self.constant.add(r, &R::mul(val, from_usize::<R>(r)));
+        }
+    }
+
+    /// sum of the first `index` elements.
+    pub fn sum(&self, index: usize) -> R::S {
+        R::add(
+            R::mul(self.linear.sum(index), from_usize::<R>(index)),
+            self.constant.sum(index),
+        )
+    }
+
+    pub fn range_sum<B>(&self, range: B) -> R::S
+    where
+        B: RangeBounds<usize>,
+    {
+        let (l, r) = to_range(range, self.size);
+        R::sub(self.sum(r), self.sum(l))
+    }
+
+    pub fn get(&self, index: usize) -> R::S {
+        self.range_sum(index..=index)
+    }
+}
+
+/// # 2D Binary Indexed Tree
+/// point add and prefix fold over an h x w grid of a commutative monoid.
+/// ```
+/// use binary_indexed_tree::BinaryIndexedTree2D;
+/// use magma::AddMagma;
+///
+/// let mut bit = BinaryIndexedTree2D::<AddMagma<i64>>::new(3, 4);
+/// bit.add(0, 1, &5);
+/// bit.add(2, 3, &7);
+/// assert_eq!(bit.sum(1, 2), 5);
+/// assert_eq!(bit.sum(3, 4), 12);
+/// assert_eq!(bit.range_sum(1..3, 2..), 7);
+/// ```
+pub struct BinaryIndexedTree2D<T>
+where
+    T: monoid::Monoid,
+    <T as magma::Magma>::S: Clone,
+{
+    data: Vec<Vec<T::S>>,
+    height: usize,
+    width: usize,
+}
+
+impl<T> BinaryIndexedTree2D<T>
+where
+    T: monoid::Monoid + magma::Commutative,
+    <T as magma::Magma>::S: Clone,
+{
+    pub fn new(h: usize, w: usize) -> BinaryIndexedTree2D<T> {
+        BinaryIndexedTree2D {
+            data: vec![vec![T::identity(); w + 1]; h + 1],
+            height: h,
+            width: w,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// fold of the cells (i', j') with i' < i and j' < j.
+    pub fn sum(&self, i: usize, j: usize) -> <T as magma::Magma>::S {
+        assert!(i <= self.height && j <= self.width);
+        let mut res = T::identity();
+        let mut i = i;
+        while i > 0 {
+            let mut j = j;
+            while j > 0 {
+                res = T::binary_operation(res, self.data[i][j].clone());
+                j &= j - 1;
+            }
+            i &= i - 1;
+        }
+        res
+    }
+
+    pub fn add(&mut self, i: usize, j: usize, val: &<T as magma::Magma>::S) {
+        assert!(i < self.height && j < self.width);
+        let mut i = i + 1;
+        while i <= self.height {
+            let mut j = j + 1;
+            while j <= self.width {
+                self.data[i][j] = T::binary_operation(val.clone(), self.data[i][j].clone());
+                j += j & j.wrapping_neg();
+            }
+            i += i & i.wrapping_neg();
+        }
+    }
+}
+
+impl<T> BinaryIndexedTree2D<T>
+where
+    T: group::Group + magma::Commutative,
+    <T as magma::Magma>::S: Clone,
+{
+    /// fold of the rectangle `rows` x `cols`.
+    pub fn range_sum<R, C>(&self, rows: R, cols: C) -> <T as magma::Magma>::S
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (u, d) = to_range(rows, self.height);
+        let (l, r) = to_range(cols, self.width);
+        let pos = T::binary_operation(self.sum(d, r), self.sum(u, l));
+        let neg = T::binary_operation(self.sum(u, r), self.sum(d, l));
+        T::binary_operation(pos, T::inverse(neg))
+    }
+}
+
+/// # Compressed 2D Binary Indexed Tree
+/// 2D BIT on a fixed set of points with arbitrary coordinates, given in advance.
+/// uses O(n log n) memory and O(log^2 n) time per operation.
+/// ```
+/// use binary_indexed_tree::CompressedBinaryIndexedTree2D;
+/// use magma::AddMagma;
+///
+/// let points = [(1_000_000_000, 3), (-5, 3), (7, -1_000_000_000)];
+/// let mut bit = CompressedBinaryIndexedTree2D::<AddMagma<i64>>::new(&points);
+/// bit.add(1_000_000_000, 3, &10);
+/// bit.add(-5, 3, &1);
+/// bit.add(7, -1_000_000_000, &100);
+/// assert_eq!(bit.sum(8, 4), 101);
+/// assert_eq!(bit.range_sum(0..=1_000_000_000, 0..10), 10);
+/// ```
+pub struct CompressedBinaryIndexedTree2D<T>
+where
+    T: monoid::Monoid,
+    <T as magma::Magma>::S: Clone,
+{
+    xs: Vec<i64>,
+    /// ys\[k\] are the sorted y coordinates of the points covered by the k-th node of xs.
+    ys: Vec<Vec<i64>>,
+    data: Vec<Vec<T::S>>,
+}
+
+impl<T> CompressedBinaryIndexedTree2D<T>
+where
+    T: monoid::Monoid + magma::Commutative,
+    <T as magma::Magma>::S: Clone,
+{
+    /// `points` are the only (x, y) that `add` accepts.
+    pub fn new(points: &[(i64, i64)]) -> CompressedBinaryIndexedTree2D<T> {
+        let mut xs = points.iter().map(|&(x, _)| x).collect::<Vec<_>>();
+        xs.sort_unstable();
+        xs.dedup();
+        let n = xs.len();
+        let mut ys = vec![vec![]; n + 1];
+        for &(x, y) in points {
+            let mut k = xs.partition_point(|&v| v < x) + 1;
+            while k <= n {
+                ys[k].push(y);
+                k += k & k.wrapping_neg();
+            }
+        }
+        for y in ys.iter_mut() {
+            y.sort_unstable();
+            y.dedup();
+        }
+        let data = ys
+            .iter()
+            .map(|y| vec![T::identity(); y.len() + 1])
+            .collect();
+        CompressedBinaryIndexedTree2D { xs, ys, data }
+    }
+
+    /// fold of the points (x', y') with x' < x and y' < y.
+    pub fn sum(&self, x: i64, y: i64) -> <T as magma::Magma>::S {
+        self.prefix(x as i128, y as i128)
+    }
+
+    /// `sum` with bounds that may exceed i64::MAX.
+    fn prefix(&self, x: i128, y: i128) -> <T as magma::Magma>::S {
+        let mut res = T::identity();
+        let mut k = self.xs.partition_point(|&v| (v as i128) < x);
+        while k > 0 {
+            let mut j = self.ys[k].partition_point(|&v| (v as i128) < y);
+            while j > 0 {
+                res = T::binary_operation(res, self.data[k][j].clone());
+                j &= j - 1;
+            }
+            k &= k - 1;
+        }
+        res
+    }
+
+    /// (x, y) must be one of the points given to `new`.
+    pub fn add(&mut self, x: i64, y: i64, val: &<T as magma::Magma>::S) {
+        let mut k = self.xs.binary_search(&x).expect("unknown point") + 1;
+        while k <= self.xs.len() {
+            let ys = &self.ys[k];
+            let mut j = ys.binary_search(&y).expect("unknown point") + 1;
+            while j <= ys.len() {
+                self.data[k][j] = T::binary_operation(val.clone(), self.data[k][j].clone());
+                j += j & j.wrapping_neg();
+            }
+            k += k & k.wrapping_neg();
+        }
+    }
+}
+
+impl<T> CompressedBinaryIndexedTree2D<T>
+where
+    T: group::Group + magma::Commutative,
+    <T as magma::Magma>::S: Clone,
+{
+    /// fold of the points in the rectangle `xr` x `yr`.
+    pub fn range_sum<R, C>(&self, xr: R, yr: C) -> <T as magma::Magma>::S
+    where
+        R: RangeBounds<i64>,
+        C: RangeBounds<i64>,
+    {
+        let (x1, x2) = to_coord_range(xr);
+        let (y1, y2) = to_coord_range(yr);
+        let pos = T::binary_operation(self.prefix(x2, y2), self.prefix(x1, y1));
+        let neg = T::binary_operation(self.prefix(x1, y2), self.prefix(x2, y1));
+        T::binary_operation(pos, T::inverse(neg))
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::ops::Bound;
 
-    use magma::AddMagma;
+    use magma::{AddMagma, XorMagma};
+
+    use modint::ModInt998244353 as Mint;
+    use semiring::AddMul;
 
-    use crate::BinaryIndexedTree;
+    use crate::{
+        BinaryIndexedTree, BinaryIndexedTree2D, CompressedBinaryIndexedTree2D, RangeAddRangeSumBit,
+    };
 
     #[test]
     fn test() {
@@ -111,4 +470,168 @@ mod test {
         assert_eq!(bit.range_sum(3..), 27);
         assert_eq!(bit.range_sum((Bound::Excluded(3), Bound::Excluded(5))), 14);
     }
+
+    #[test]
+    fn test_empty_range() {
+        let bit = BinaryIndexedTree::<AddMagma<i32>>::from_vec(&vec![3, 1, 4]);
+        assert_eq!(bit.range_sum(3..), 0);
+        assert_eq!(bit.range_sum(1..1), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reversed_range() {
+        let bit = BinaryIndexedTree::<AddMagma<i32>>::from_vec(&vec![3, 1, 4]);
+        bit.range_sum((Bound::Included(2), Bound::Excluded(1)));
+    }
+
+    #[test]
+    fn test_get_set() {
+        let v = vec![3, 1, 4, 1, 5, 9, 2];
+        let mut bit = BinaryIndexedTree::<AddMagma<i64>>::from_vec(&v);
+        for (i, &x) in v.iter().enumerate() {
+            assert_eq!(bit.get(i), x);
+        }
+        bit.set(2, -7);
+        bit.set(6, 0);
+        assert_eq!(bit.get(2), -7);
+        assert_eq!(bit.range_sum(..), 3 + 1 - 7 + 1 + 5 + 9);
+    }
+
+    #[test]
+    fn test_range_add_range_sum() {
+        let mut naive = vec![3i64, 1, 4, 1, 5, 9, 2, 6];
+        let n = naive.len();
+        let mut bit = RangeAddRangeSumBit::<AddMul<i64>>::from_vec(&naive);
+        for (t, (l, r)) in [(0, 8), (2, 5), (7, 8), (3, 3), (0, 1), (4, 8)]
+            .into_iter()
+            .enumerate()
+        {
+            let x = t as i64 * 3 - 5;
+            bit.range_add(l..r, x);
+            naive[l..r].iter_mut().for_each(|a| *a += x);
+            for l in 0..=n {
+                for r in l..=n {
+                    assert_eq!(bit.range_sum(l..r), naive[l..r].iter().sum::<i64>());
+                }
+            }
+        }
+        assert_eq!(bit.get(4), naive[4]);
+
+        let mut bit = RangeAddRangeSumBit::<AddMul<Mint>>::new(5);
+        bit.range_add(1..=3, Mint::new(998244352));
+        assert_eq!(bit.range_sum(..), Mint::new(998244353 - 3));
+    }
+
+    #[test]
+    fn test_2d() {
+        let (h, w) = (5, 7);
+        let mut naive = vec![vec![0i64; w]; h];
+        let mut bit = BinaryIndexedTree2D::<AddMagma<i64>>::new(h, w);
+        let points = (0..20i64)
+            .map(|t| (t * 7 % 11 % h as i64, t * 5 % 13 % w as i64, t - 8))
+            .collect::<Vec<_>>();
+        let mut compressed = CompressedBinaryIndexedTree2D::<AddMagma<i64>>::new(
+            &points
+                .iter()
+                .map(|&(x, y, _)| (x * 1_000_000 - 2, -y * 1_000_000))
+                .collect::<Vec<_>>(),
+        );
+        for &(x, y, v) in &points {
+            naive[x as usize][y as usize] += v;
+            bit.add(x as usize, y as usize, &v);
+            compressed.add(x * 1_000_000 - 2, -y * 1_000_000, &v);
+        }
+        for u in 0..=h {
+            for d in u..=h {
+                for l in 0..=w {
+                    for r in l..=w {
+                        let expected = naive[u..d]
+                            .iter()
+                            .map(|row| row[l..r].iter().sum::<i64>())
+                            .sum::<i64>();
+                        assert_eq!(bit.range_sum(u..d, l..r), expected);
+                        // y is mirrored in the compressed tree.
+                        let (x1, x2) = (u as i64 * 1_000_000 - 2, d as i64 * 1_000_000 - 2);
+                        let (y1, y2) = (-(r as i64 - 1) * 1_000_000, -(l as i64 - 1) * 1_000_000);
+                        assert_eq!(compressed.range_sum(x1..x2, y1..y2), expected);
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            compressed.range_sum(.., ..),
+            points.iter().map(|p| p.2).sum::<i64>()
+        );
+        assert_eq!(bit.sum(h, w), compressed.sum(i64::MAX, i64::MAX));
+    }
+
+    #[test]
+    fn test_compressed_extremes() {
+        let points = [
+            (i64::MIN, i64::MAX),
+            (i64::MAX, i64::MIN),
+            (i64::MAX, i64::MAX),
+        ];
+        let mut bit = CompressedBinaryIndexedTree2D::<AddMagma<i64>>::new(&points);
+        for (i, &(x, y)) in points.iter().enumerate() {
+            bit.add(x, y, &(1 << i));
+        }
+        assert_eq!(bit.range_sum(.., ..), 7);
+        assert_eq!(bit.range_sum(i64::MAX.., ..), 6);
+        assert_eq!(bit.range_sum(..=i64::MAX, i64::MAX..=i64::MAX), 5);
+        assert_eq!(bit.range_sum(..i64::MAX, ..), 1);
+        assert_eq!(
+            bit.range_sum((Bound::Excluded(i64::MAX), Bound::Unbounded), ..),
+            0
+        );
+        assert_eq!(bit.sum(i64::MAX, i64::MAX), 0);
+    }
+
+    #[test]
+    fn test_binary_search() {
+        let v = vec![3, 0, 4, 1, 5, 0, 2];
+        let bit = BinaryIndexedTree::<AddMagma<u32>>::from_vec(&v);
+        for x in 0..=20 {
+            let r = (0..=v.len()).rev().find(|&r| bit.sum(r) <= x).unwrap();
+            assert_eq!(bit.max_right(|&s| s <= x), r);
+            let i = (0..v.len())
+                .find(|&i| bit.sum(i + 1) >= x)
+                .unwrap_or(v.len());
+            assert_eq!(bit.lower_bound(&x), i);
+        }
+        assert_eq!(bit.lower_bound(&0), 0);
+        assert!(BinaryIndexedTree::<AddMagma<u32>>::new(0).kth(&0).is_none());
+    }
+
+    #[test]
+    fn test_kth() {
+        let mut bit = BinaryIndexedTree::<AddMagma<usize>>::new(10);
+        let mut multiset = vec![7, 2, 2, 9, 0, 5];
+        for &x in &multiset {
+            bit.add(x, &1);
+        }
+        multiset.sort();
+        for (k, &x) in multiset.iter().enumerate() {
+            assert_eq!(bit.kth(&k), Some(x));
+        }
+        assert_eq!(bit.kth(&multiset.len()), None);
+    }
+
+    #[test]
+    fn test_xor() {
+        let v = vec![3u32, 1, 4, 1, 5, 9, 2, 6];
+        let mut bit = BinaryIndexedTree::<XorMagma<u32>>::from_vec(&v);
+        for l in 0..v.len() {
+            for r in l + 1..=v.len() {
+                assert_eq!(
+                    bit.range_sum(l..r),
+                    v[l..r].iter().fold(0, |acc, x| acc ^ x)
+                );
+            }
+        }
+        bit.add(2, &7);
+        assert_eq!(bit.range_sum(2..=2), 3);
+        assert_eq!(bit.range_sum(1..4), 1 ^ 3 ^ 1);
+    }
 }
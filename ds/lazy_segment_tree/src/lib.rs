@@ -0,0 +1,335 @@
+use std::ops::RangeBounds;
+
+use action::Action;
+use magma::Magma;
+use monoid::Monoid;
+use range_bounds::to_range;
+
+type MS<A> = <<A as Action>::M as Magma>::S;
+type FS<A> = <<A as Action>::F as Magma>::S;
+
+/// # Lazy Segment Tree
+/// range apply and range fold, where the maps act on the monoid through `A`.
+/// ```
+/// use action::AddMin;
+/// use lazy_segment_tree::LazySegmentTree;
+///
+/// let mut seg = LazySegmentTree::<AddMin<i64>>::from_vec(&[3, 1, 4, 1, 5]);
+/// seg.apply(1..3, 10);
+/// assert_eq!(seg.prod(..), 1);
+/// assert_eq!(seg.prod(0..3), 3);
+/// ```
+pub struct LazySegmentTree<A>
+where
+    A: Action,
+    MS<A>: Clone,
+    FS<A>: Clone,
+{
+    size: usize,
+    log: u32,
+    leaf: usize,
+    data: Vec<MS<A>>,
+    lazy: Vec<FS<A>>,
+}
+
+impl<A> LazySegmentTree<A>
+where
+    A: Action,
+    MS<A>: Clone,
+    FS<A>: Clone,
+{
+    pub fn new(n: usize) -> LazySegmentTree<A> {
+        let leaf = n.next_power_of_two();
+        LazySegmentTree {
+            size: n,
+            log: leaf.trailing_zeros(),
+            leaf,
+            data: vec![A::M::identity(); 2 * leaf],
+            lazy: vec![A::F::identity(); leaf],
+        }
+    }
+
+    pub fn from_vec(v: &[MS<A>]) -> LazySegmentTree<A> {
+        let mut res = Self::new(v.len());
+        for (i, x) in v.iter().enumerate() {
+            res.data[res.leaf + i] = x.clone();
+        }
+        for i in (1..res.leaf).rev() {
+            res.update(i);
+        }
+        res
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    fn update(&mut self, k: usize) {
+        self.data[k] =
+            A::M::binary_operation(self.data[2 * k].clone(), self.data[2 * k + 1].clone());
+    }
+
+    fn all_apply(&mut self, k: usize, f: &FS<A>) {
+        self.data[k] = A::act(f, self.data[k].clone());
+        if k < self.leaf {
+            self.lazy[k] = A::F::binary_operation(self.lazy[k].clone(), f.clone());
+        }
+    }
+
+    fn push(&mut self, k: usize) {
+        let f = std::mem::replace(&mut self.lazy[k], A::F::identity());
+        self.all_apply(2 * k, &f);
+        self.all_apply(2 * k + 1, &f);
+    }
+
+    /// push all the pending maps on the path from the root to leaf `k`.
+    fn push_path(&mut self, k: usize) {
+        for i in (1..=self.log).rev() {
+            self.push(k >> i);
+        }
+    }
+
+    fn update_path(&mut self, k: usize) {
+        for i in 1..=self.log {
+            self.update(k >> i);
+        }
+    }
+
+    pub fn set(&mut self, index: usize, val: MS<A>) {
+        assert!(index < self.size);
+        let k = index + self.leaf;
+        self.push_path(k);
+        self.data[k] = val;
+        self.update_path(k);
+    }
+
+    pub fn get(&mut self, index: usize) -> MS<A> {
+        assert!(index < self.size);
+        let k = index + self.leaf;
+        self.push_path(k);
+        self.data[k].clone()
+    }
+
+    /// fold of elements in `range`, from left to right.
+    pub fn prod<R>(&mut self, range: R) -> MS<A>
+    where
+        R: RangeBounds<usize>,
+    {
+        let (lower, upper) = to_range(range, self.size);
+        if lower == upper {
+            return A::M::identity();
+        }
+        let mut l = lower + self.leaf;
+        let mut r = upper + self.leaf;
+        for i in (1..=self.log).rev() {
+            if ((l >> i) << i) != l {
+                self.push(l >> i);
+            }
+            if ((r >> i) << i) != r {
+                self.push((r - 1) >> i);
+            }
+        }
+        let mut sml = A::M::identity();
+        let mut smr = A::M::identity();
+        while l < r {
+            if l & 1 == 1 {
+                sml = A::M::binary_operation(sml, self.data[l].clone());
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                smr = A::M::binary_operation(self.data[r].clone(), smr);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        A::M::binary_operation(sml, smr)
+    }
+
+    pub fn all_prod(&self) -> MS<A> {
+        self.data[1].clone()
+    }
+
+    /// apply `f` to every element in `range`.
+    pub fn apply<R>(&mut self, range: R, f: FS<A>)
+    where
+        R: RangeBounds<usize>,
+    {
+        let (lower, upper) = to_range(range, self.size);
+        if lower == upper {
+            return;
+        }
+        let l0 = lower + self.leaf;
+        let r0 = upper + self.leaf;
+        for i in (1..=self.log).rev() {
+            if ((l0 >> i) << i) != l0 {
+                self.push(l0 >> i);
+            }
+            if ((r0 >> i) << i) != r0 {
+                self.push((r0 - 1) >> i);
+            }
+        }
+        let mut l = l0;
+        let mut r = r0;
+        while l < r {
+            if l & 1 == 1 {
+                self.all_apply(l, &f);
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                self.all_apply(r, &f);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        for i in 1..=self.log {
+            if ((l0 >> i) << i) != l0 {
+                self.update(l0 >> i);
+            }
+            if ((r0 >> i) << i) != r0 {
+                self.update((r0 - 1) >> i);
+            }
+        }
+    }
+
+    /// return the maximum r such that `f(prod(l..r))` holds.
+    /// `f` must be monotone and `f(identity)` must be true.
+    pub fn max_right<F>(&mut self, l: usize, f: F) -> usize
+    where
+        F: Fn(&MS<A>) -> bool,
+    {
+        assert!(l <= self.size);
+        assert!(f(&A::M::identity()));
+        if l == self.size {
+            return self.size;
+        }
+        let mut l = l + self.leaf;
+        self.push_path(l);
+        let mut sm = A::M::identity();
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            let nxt = A::M::binary_operation(sm.clone(), self.data[l].clone());
+            if !f(&nxt) {
+                while l < self.leaf {
+                    self.push(l);
+                    l *= 2;
+                    let nxt = A::M::binary_operation(sm.clone(), self.data[l].clone());
+                    if f(&nxt) {
+                        sm = nxt;
+                        l += 1;
+                    }
+                }
+                return l - self.leaf;
+            }
+            sm = nxt;
+            l += 1;
+            if l & l.wrapping_neg() == l {
+                return self.size;
+            }
+        }
+    }
+
+    /// return the minimum l such that `f(prod(l..r))` holds.
+    /// `f` must be monotone and `f(identity)` must be true.
+    pub fn min_left<F>(&mut self, r: usize, f: F) -> usize
+    where
+        F: Fn(&MS<A>) -> bool,
+    {
+        assert!(r <= self.size);
+        assert!(f(&A::M::identity()));
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.leaf;
+        self.push_path(r - 1);
+        let mut sm = A::M::identity();
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let nxt = A::M::binary_operation(self.data[r].clone(), sm.clone());
+            if !f(&nxt) {
+                while r < self.leaf {
+                    self.push(r);
+                    r = 2 * r + 1;
+                    let nxt = A::M::binary_operation(self.data[r].clone(), sm.clone());
+                    if f(&nxt) {
+                        sm = nxt;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.leaf;
+            }
+            sm = nxt;
+            if r & r.wrapping_neg() == r {
+                return 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use action::{AddMax, AddMin, AffineSum, AssignSum};
+
+    use crate::LazySegmentTree;
+
+    #[test]
+    fn test_add_max() {
+        let mut v = vec![3, 1, 4, 1, 5, 9, 2, 6];
+        let mut seg = LazySegmentTree::<AddMax<i32>>::from_vec(&v);
+        let ops = [(0, 3, 2), (2, 7, -4), (5, 6, 10), (1, 8, 1)];
+        for &(l, r, x) in &ops {
+            seg.apply(l..r, x);
+            for e in &mut v[l..r] {
+                *e += x;
+            }
+            for l in 0..=v.len() {
+                for r in l..=v.len() {
+                    let naive = v[l..r].iter().copied().max().unwrap_or(i32::MIN);
+                    assert_eq!(seg.prod(l..r), naive);
+                }
+            }
+        }
+        assert_eq!(seg.max_right(0, |&x| x < 10), 5);
+        assert_eq!(seg.min_left(8, |&x| x < 10), 6);
+        seg.set(5, 0);
+        assert_eq!(seg.get(5), 0);
+        assert_eq!(seg.all_prod(), 7);
+    }
+
+    #[test]
+    fn test_zero_elements() {
+        let mut seg = LazySegmentTree::<AddMax<i64>>::from_vec(&[0, 0, 0]);
+        seg.apply(.., 5);
+        assert_eq!(seg.prod(..), 5);
+        let mut seg = LazySegmentTree::<AddMin<u64>>::from_vec(&[0, 0, 0]);
+        seg.apply(1.., 5);
+        assert_eq!(seg.prod(1..), 5);
+        assert_eq!(seg.prod(..), 0);
+    }
+
+    #[test]
+    fn test_sum() {
+        let mut seg = LazySegmentTree::<AffineSum<i64>>::from_vec(&[(1, 1); 5]);
+        seg.apply(1..4, (2, 3));
+        assert_eq!(seg.prod(..), (1 + 5 * 3 + 1, 5));
+        seg.apply(..=1, (-1, 0));
+        assert_eq!(seg.prod(0..2), (-6, 2));
+        assert_eq!(seg.get(2), (5, 1));
+
+        let mut seg = LazySegmentTree::<AssignSum<u64>>::from_vec(&[(2, 1); 6]);
+        seg.apply(2..5, Some(7));
+        seg.apply(4.., Some(1));
+        assert_eq!(seg.prod(..), (2 + 2 + 7 + 7 + 1 + 1, 6));
+        assert_eq!(seg.max_right(0, |&(s, _)| s <= 11), 3);
+    }
+}
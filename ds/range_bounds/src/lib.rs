@@ -0,0 +1,81 @@
+use std::ops::{Bound, RangeBounds};
+
+/// half-open bounds (l, r) of `range` over the indices 0..n.
+/// panics unless l <= r <= n.
+/// ```
+/// use range_bounds::to_range;
+///
+/// assert_eq!(to_range(2..=4, 10), (2, 5));
+/// assert_eq!(to_range(.., 10), (0, 10));
+/// assert_eq!(to_range(10.., 10), (10, 10));
+/// ```
+pub fn to_range<R: RangeBounds<usize>>(range: R, n: usize) -> (usize, usize) {
+    let lower = match range.start_bound() {
+        Bound::Included(x) => *x,
+        Bound::Excluded(x) => *x + 1,
+        Bound::Unbounded => 0,
+    };
+    let upper = match range.end_bound() {
+        Bound::Included(x) => *x + 1,
+        Bound::Excluded(x) => *x,
+        Bound::Unbounded => n,
+    };
+    assert!(lower <= upper && upper <= n, "invalid range");
+    (lower, upper)
+}
+
+/// half-open bounds (l, r) of `range` over all i64 coordinates.
+/// i128 so that i64::MAX can be included. panics unless l <= r.
+/// ```
+/// use range_bounds::to_coord_range;
+///
+/// assert_eq!(to_coord_range(-3..=4), (-3, 5));
+/// assert_eq!(to_coord_range(..), (i64::MIN as i128, i64::MAX as i128 + 1));
+/// ```
+pub fn to_coord_range<R: RangeBounds<i64>>(range: R) -> (i128, i128) {
+    let lower = match range.start_bound() {
+        Bound::Included(x) => *x as i128,
+        Bound::Excluded(x) => *x as i128 + 1,
+        Bound::Unbounded => i64::MIN as i128,
+    };
+    let upper = match range.end_bound() {
+        Bound::Included(x) => *x as i128 + 1,
+        Bound::Excluded(x) => *x as i128,
+        Bound::Unbounded => i64::MAX as i128 + 1,
+    };
+    assert!(lower <= upper, "invalid range");
+    (lower, upper)
+}
+
+#[cfg(test)]
+mod test {
+    use std::ops::Bound;
+
+    use crate::{to_coord_range, to_range};
+
+    #[test]
+    fn test_to_range() {
+        assert_eq!(to_range(3..3, 5), (3, 3));
+        assert_eq!(
+            to_range((Bound::Excluded(1), Bound::Excluded(4)), 5),
+            (2, 4)
+        );
+        assert_eq!(
+            to_coord_range((Bound::Excluded(i64::MAX), Bound::Unbounded)),
+            (i64::MAX as i128 + 1, i64::MAX as i128 + 1)
+        );
+        assert_eq!(to_coord_range(i64::MAX..=i64::MAX).1, i64::MAX as i128 + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid range")]
+    fn test_out_of_bounds() {
+        to_range(2..=5, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid range")]
+    fn test_reversed() {
+        to_coord_range((Bound::Included(1), Bound::Excluded(0)));
+    }
+}
@@ -0,0 +1,245 @@
+use std::ops::RangeBounds;
+
+use monoid::Monoid;
+use range_bounds::to_range;
+
+/// # Segment Tree
+/// point set and range fold over a (not necessarily commutative) monoid.
+/// ```
+/// use magma::AddMagma;
+/// use segment_tree::SegmentTree;
+///
+/// let mut seg = SegmentTree::<AddMagma<i64>>::from_vec(&[3, 1, 4, 1, 5]);
+/// assert_eq!(seg.prod(1..4), 6);
+/// seg.set(2, 10);
+/// assert_eq!(seg.prod(..), 20);
+/// assert_eq!(seg.max_right(0, |&x| x <= 14), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SegmentTree<T>
+where
+    T: Monoid,
+    T::S: Clone,
+{
+    size: usize,
+    leaf: usize,
+    data: Vec<T::S>,
+}
+
+impl<T> SegmentTree<T>
+where
+    T: Monoid,
+    T::S: Clone,
+{
+    pub fn new(n: usize) -> SegmentTree<T> {
+        let leaf = n.next_power_of_two();
+        SegmentTree {
+            size: n,
+            leaf,
+            data: vec![T::identity(); 2 * leaf],
+        }
+    }
+
+    pub fn from_vec(v: &[T::S]) -> SegmentTree<T> {
+        let mut res = Self::new(v.len());
+        for (i, x) in v.iter().enumerate() {
+            res.data[res.leaf + i] = x.clone();
+        }
+        for i in (1..res.leaf).rev() {
+            res.update(i);
+        }
+        res
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    fn update(&mut self, k: usize) {
+        self.data[k] = T::binary_operation(self.data[2 * k].clone(), self.data[2 * k + 1].clone());
+    }
+
+    pub fn set(&mut self, index: usize, val: T::S) {
+        assert!(index < self.size);
+        let mut k = index + self.leaf;
+        self.data[k] = val;
+        while k > 1 {
+            k >>= 1;
+            self.update(k);
+        }
+    }
+
+    pub fn get(&self, index: usize) -> T::S {
+        assert!(index < self.size);
+        self.data[index + self.leaf].clone()
+    }
+
+    /// fold of elements in `range`, from left to right.
+    pub fn prod<R>(&self, range: R) -> T::S
+    where
+        R: RangeBounds<usize>,
+    {
+        let (lower, upper) = to_range(range, self.size);
+        let mut l = lower + self.leaf;
+        let mut r = upper + self.leaf;
+        let mut sml = T::identity();
+        let mut smr = T::identity();
+        while l < r {
+            if l & 1 == 1 {
+                sml = T::binary_operation(sml, self.data[l].clone());
+                l += 1;
+            }
+            if r & 1 == 1 {
+                r -= 1;
+                smr = T::binary_operation(self.data[r].clone(), smr);
+            }
+            l >>= 1;
+            r >>= 1;
+        }
+        T::binary_operation(sml, smr)
+    }
+
+    pub fn all_prod(&self) -> T::S {
+        self.data[1].clone()
+    }
+
+    /// return the maximum r such that `f(prod(l..r))` holds.
+    /// `f` must be monotone and `f(identity)` must be true.
+    pub fn max_right<F>(&self, l: usize, f: F) -> usize
+    where
+        F: Fn(&T::S) -> bool,
+    {
+        assert!(l <= self.size);
+        assert!(f(&T::identity()));
+        if l == self.size {
+            return self.size;
+        }
+        let mut l = l + self.leaf;
+        let mut sm = T::identity();
+        loop {
+            while l & 1 == 0 {
+                l >>= 1;
+            }
+            let nxt = T::binary_operation(sm.clone(), self.data[l].clone());
+            if !f(&nxt) {
+                while l < self.leaf {
+                    l *= 2;
+                    let nxt = T::binary_operation(sm.clone(), self.data[l].clone());
+                    if f(&nxt) {
+                        sm = nxt;
+                        l += 1;
+                    }
+                }
+                return l - self.leaf;
+            }
+            sm = nxt;
+            l += 1;
+            if l & l.wrapping_neg() == l {
+                return self.size;
+            }
+        }
+    }
+
+    /// return the minimum l such that `f(prod(l..r))` holds.
+    /// `f` must be monotone and `f(identity)` must be true.
+    pub fn min_left<F>(&self, r: usize, f: F) -> usize
+    where
+        F: Fn(&T::S) -> bool,
+    {
+        assert!(r <= self.size);
+        assert!(f(&T::identity()));
+        if r == 0 {
+            return 0;
+        }
+        let mut r = r + self.leaf;
+        let mut sm = T::identity();
+        loop {
+            r -= 1;
+            while r > 1 && r & 1 == 1 {
+                r >>= 1;
+            }
+            let nxt = T::binary_operation(self.data[r].clone(), sm.clone());
+            if !f(&nxt) {
+                while r < self.leaf {
+                    r = 2 * r + 1;
+                    let nxt = T::binary_operation(self.data[r].clone(), sm.clone());
+                    if f(&nxt) {
+                        sm = nxt;
+                        r -= 1;
+                    }
+                }
+                return r + 1 - self.leaf;
+            }
+            sm = nxt;
+            if r & r.wrapping_neg() == r {
+                return 0;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use magma::{Magma, MaxMagma, MinMagma};
+    use monoid::Monoid;
+
+    use crate::SegmentTree;
+
+    #[test]
+    fn test_max_min() {
+        let v = vec![3, 1, 4, 1, 5, 9, 2];
+        let mut seg = SegmentTree::<MaxMagma<i32>>::from_vec(&v);
+        assert_eq!(seg.prod(0..3), 4);
+        assert_eq!(seg.prod(2..=4), 5);
+        assert_eq!(seg.prod(3..3), i32::MIN);
+        assert_eq!(seg.all_prod(), 9);
+        seg.set(5, 0);
+        assert_eq!(seg.get(5), 0);
+        assert_eq!(seg.prod(3..), 5);
+        assert_eq!(seg.max_right(0, |&x| x < 5), 4);
+        assert_eq!(seg.max_right(7, |&x| x < 5), 7);
+        assert_eq!(seg.min_left(7, |&x| x < 5), 5);
+        assert_eq!(seg.min_left(4, |&x| x < 5), 0);
+
+        let seg = SegmentTree::<MinMagma<u64>>::from_vec(&[5, 3, 8, 2, 7]);
+        assert_eq!(seg.prod(..2), 3);
+        assert_eq!(seg.prod(2..), 2);
+        assert_eq!(seg.max_right(2, |&x| x >= 3), 3);
+    }
+
+    /// x -> ax + b, composed from left to right.
+    struct Affine;
+    impl Magma for Affine {
+        type S = (i64, i64);
+        fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+            (lhs.0 * rhs.0, lhs.1 * rhs.0 + rhs.1)
+        }
+    }
+    impl Monoid for Affine {
+        fn identity() -> Self::S {
+            (1, 0)
+        }
+    }
+
+    #[test]
+    fn test_non_commutative() {
+        let v = vec![(2, 1), (3, 0), (1, 5), (-1, 2)];
+        let mut seg = SegmentTree::<Affine>::from_vec(&v);
+        for l in 0..=v.len() {
+            for r in l..=v.len() {
+                let naive = v[l..r]
+                    .iter()
+                    .fold((1, 0), |acc, &f| Affine::binary_operation(acc, f));
+                assert_eq!(seg.prod(l..r), naive);
+            }
+        }
+        seg.set(1, (0, 7));
+        assert_eq!(seg.prod(..), (0, -12 + 2));
+        assert_eq!(seg.max_right(0, |&(a, _)| a != 0), 1);
+        assert_eq!(seg.min_left(4, |&(a, _)| a != 0), 2);
+    }
+}
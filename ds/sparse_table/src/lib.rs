@@ -0,0 +1,190 @@
+use std::ops::RangeBounds;
+
+use magma::{Idempotent, Magma};
+use monoid::Monoid;
+use range_bounds::to_range;
+
+/// # Sparse Table
+/// static range fold over an idempotent monoid in O(1) after O(n log n) build.
+/// ```
+/// use magma::MinMagma;
+/// use sparse_table::SparseTable;
+///
+/// let st = SparseTable::<MinMagma<i64>>::from_vec(&[3, 1, 4, 1, 5, 9, 2]);
+/// assert_eq!(st.prod(2..5), 1);
+/// assert_eq!(st.prod(4..), 2);
+/// assert_eq!(st.prod(3..3), i64::MAX);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseTable<T>
+where
+    T: Monoid + Idempotent,
+    T::S: Clone,
+{
+    /// table\[k\]\[i\] = fold of i..i + 2^k.
+    table: Vec<Vec<T::S>>,
+}
+
+impl<T> SparseTable<T>
+where
+    T: Monoid + Idempotent,
+    T::S: Clone,
+{
+    pub fn from_vec(v: &[T::S]) -> SparseTable<T> {
+        let mut table = vec![v.to_vec()];
+        let mut k = 1;
+        while 1 << k <= v.len() {
+            let prev = &table[k - 1];
+            let row = (0..=v.len() - (1 << k))
+                .map(|i| T::binary_operation(prev[i].clone(), prev[i + (1 << (k - 1))].clone()))
+                .collect();
+            table.push(row);
+            k += 1;
+        }
+        SparseTable { table }
+    }
+
+    pub fn len(&self) -> usize {
+        self.table[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table[0].is_empty()
+    }
+
+    /// fold of elements in `range`.
+    pub fn prod<R>(&self, range: R) -> T::S
+    where
+        R: RangeBounds<usize>,
+    {
+        let (l, r) = to_range(range, self.len());
+        if l == r {
+            return T::identity();
+        }
+        let k = (r - l).ilog2() as usize;
+        T::binary_operation(
+            self.table[k][l].clone(),
+            self.table[k][r - (1 << k)].clone(),
+        )
+    }
+}
+
+/// # Disjoint Sparse Table
+/// static range fold over an associative magma (semigroup) in O(1) after O(n log n) build.
+/// ```
+/// use magma::AddMagma;
+/// use sparse_table::DisjointSparseTable;
+///
+/// let st = DisjointSparseTable::<AddMagma<i64>>::from_vec(&[3, 1, 4, 1, 5, 9, 2]);
+/// assert_eq!(st.prod(2..5), 10);
+/// assert_eq!(st.prod(..), 25);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DisjointSparseTable<T>
+where
+    T: Magma,
+    T::S: Clone,
+{
+    /// for the blocks of size 2^(k+1), table\[k\]\[i\] is the fold from i to the middle
+    /// of its block for the left half and from the middle to i for the right half.
+    table: Vec<Vec<T::S>>,
+}
+
+impl<T> DisjointSparseTable<T>
+where
+    T: Magma,
+    T::S: Clone,
+{
+    pub fn from_vec(v: &[T::S]) -> DisjointSparseTable<T> {
+        let n = v.len();
+        let mut table = vec![v.to_vec()];
+        let mut k = 1;
+        while 1 << k < n {
+            let w = 1 << k;
+            let mut row = v.to_vec();
+            for mid in (w..n).step_by(2 * w) {
+                for i in (mid - w..mid - 1).rev() {
+                    row[i] = T::binary_operation(v[i].clone(), row[i + 1].clone());
+                }
+                for i in mid + 1..n.min(mid + w) {
+                    row[i] = T::binary_operation(row[i - 1].clone(), v[i].clone());
+                }
+            }
+            table.push(row);
+            k += 1;
+        }
+        DisjointSparseTable { table }
+    }
+
+    pub fn len(&self) -> usize {
+        self.table[0].len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.table[0].is_empty()
+    }
+
+    /// fold of elements in `range`, from left to right. `range` must not be empty.
+    pub fn prod<R>(&self, range: R) -> T::S
+    where
+        R: RangeBounds<usize>,
+    {
+        let (l, r) = to_range(range, self.len());
+        assert!(l < r);
+        let r = r - 1;
+        if l == r {
+            return self.table[0][l].clone();
+        }
+        let k = (l ^ r).ilog2() as usize;
+        T::binary_operation(self.table[k][l].clone(), self.table[k][r].clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use magma::{Affine, ArgMax, GcdMagma, Magma, Rev};
+
+    use crate::{DisjointSparseTable, SparseTable};
+
+    #[test]
+    fn test_sparse_table() {
+        let v = (0..50u64).map(|i| i * 37 % 23 * 6).collect::<Vec<_>>();
+        let st = SparseTable::<GcdMagma<u64>>::from_vec(&v);
+        for l in 0..=v.len() {
+            for r in l..=v.len() {
+                let naive = v[l..r]
+                    .iter()
+                    .fold(0, |acc, &x| GcdMagma::<u64>::binary_operation(acc, x));
+                assert_eq!(st.prod(l..r), naive);
+            }
+        }
+        let w = v
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| (x, i))
+            .collect::<Vec<_>>();
+        let st = SparseTable::<ArgMax<u64>>::from_vec(&w);
+        assert_eq!(st.prod(..), (132, 18));
+        assert!(SparseTable::<ArgMax<u64>>::from_vec(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_disjoint_sparse_table() {
+        let v = (0..50i64).map(|i| (i % 3 - 1, i % 7)).collect::<Vec<_>>();
+        for st in [
+            DisjointSparseTable::<Affine<i64>>::from_vec(&v),
+            DisjointSparseTable::<Affine<i64>>::from_vec(&v[..33]),
+        ] {
+            for l in 0..st.len() {
+                for r in l + 1..=st.len() {
+                    let naive = v[l + 1..r]
+                        .iter()
+                        .fold(v[l], |acc, &f| Affine::<i64>::binary_operation(acc, f));
+                    assert_eq!(st.prod(l..r), naive);
+                }
+            }
+        }
+        let st = DisjointSparseTable::<Rev<Affine<i64>>>::from_vec(&v);
+        assert_eq!(st.prod(3..=4), Affine::<i64>::binary_operation(v[4], v[3]));
+    }
+}
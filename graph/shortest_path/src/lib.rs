@@ -84,3 +84,26 @@ impl<T: Clone> ShortestPath<T> for graph_base::UndirectedGraph<T> {
             .collect()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::num::Saturating;
+
+    use graph_base::{DirectedGraph, GraphBase};
+
+    use crate::ShortestPath;
+
+    #[test]
+    fn test_dijkstra_saturating() {
+        // u64::MAX marks a blocked edge, and longer paths through it must not overflow.
+        let blocked = Saturating(u64::MAX);
+        let mut g = DirectedGraph::new(4);
+        g.add_weighted_edge(0, 1, &blocked);
+        g.add_weighted_edge(1, 3, &Saturating(5));
+        g.add_weighted_edge(0, 2, &Saturating(3));
+        g.add_weighted_edge(2, 3, &Saturating(4));
+        let res = g.dijkstra(0);
+        assert_eq!(res.dist, [0, u64::MAX, 3, 7].map(|d| Some(Saturating(d))));
+        assert_eq!(res.prv[3], Some(2));
+    }
+}
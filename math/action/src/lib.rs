@@ -0,0 +1,224 @@
+use std::marker::PhantomData;
+
+use magma::{AddMagma, Affine, Magma, MaxMagma, MinMagma};
+use monoid::Monoid;
+
+/// monoid `F` of maps acting on monoid `M`.
+///
+/// `F::binary_operation(f, g)` is the composition applying `f` first and then `g`,
+/// and `act(f, _)` must be a monoid homomorphism on `M`.
+pub trait Action {
+    type M: Monoid;
+    type F: Monoid;
+    fn act(f: &<Self::F as Magma>::S, x: <Self::M as Magma>::S) -> <Self::M as Magma>::S;
+}
+
+#[doc(hidden)]
+pub use magma;
+
+/// define a marker type with an `Action` impl of `map` on `monoid`.
+/// the first parameter of `act` is a reference to the map.
+/// ```
+/// use action::{define_action, Action};
+/// use monoid::define_monoid;
+///
+/// define_monoid! {
+///     /// (sum, length)
+///     Sum: (i64, i64),
+///     identity: (0, 0),
+///     operation: |(a, n), (b, m)| (a + b, n + m),
+///     commutative,
+/// }
+///
+/// define_monoid! {
+///     Shift: i64,
+///     identity: 0,
+///     operation: |a, b| a + b,
+///     commutative,
+/// }
+///
+/// define_action! {
+///     /// add the same value to every element.
+///     AddSum,
+///     monoid: Sum,
+///     map: Shift,
+///     act: |&f, (s, n)| (s + f * n, n),
+/// }
+///
+/// assert_eq!(AddSum::act(&3, (10, 4)), (22, 4));
+/// ```
+#[macro_export]
+macro_rules! define_action {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident,
+        monoid: $m:ty,
+        map: $f:ty,
+        act: |$fp:pat_param, $xp:pat_param| $act:expr $(,)?
+    ) => {
+        $(#[$attr])*
+        $vis struct $name;
+
+        impl $crate::Action for $name {
+            type M = $m;
+            type F = $f;
+            fn act(
+                $fp: &<$f as $crate::magma::Magma>::S,
+                $xp: <$m as $crate::magma::Magma>::S,
+            ) -> <$m as $crate::magma::Magma>::S {
+                $act
+            }
+        }
+    };
+}
+
+/// sum with the length of the segment. element is (sum, length).
+pub struct AddWithLenMagma<T>(PhantomData<T>);
+/// assignment. `None` is the identity map and the later assignment wins.
+pub struct AssignMagma<T>(PhantomData<T>);
+/// composition of x -> ax + b. element is (a, b).
+pub type AffineMagma<T> = Affine<T>;
+
+/// range add on range min. `T::MAX` is regarded as +∞.
+pub struct AddMin<T>(PhantomData<T>);
+/// range add on range max over signed integers. `T::MIN` is regarded as -∞.
+pub struct AddMax<T>(PhantomData<T>);
+pub struct AssignSum<T>(PhantomData<T>);
+pub struct AffineSum<T>(PhantomData<T>);
+
+macro_rules! impl_add_min {
+    ($t:ty) => {
+        impl Action for AddMin<$t> {
+            type M = MinMagma<$t>;
+            type F = AddMagma<$t>;
+            fn act(f: &$t, x: $t) -> $t {
+                if x == <$t>::MAX {
+                    x
+                } else {
+                    x + *f
+                }
+            }
+        }
+    };
+}
+
+// only for signed types, since -∞ (`T::MIN`) would coincide with the real value 0.
+macro_rules! impl_add_max {
+    ($t:ty) => {
+        impl Action for AddMax<$t> {
+            type M = MaxMagma<$t>;
+            type F = AddMagma<$t>;
+            fn act(f: &$t, x: $t) -> $t {
+                if x == <$t>::MIN {
+                    x
+                } else {
+                    x + *f
+                }
+            }
+        }
+    };
+}
+
+impl_add_min!(usize);
+impl_add_min!(i8);
+impl_add_min!(i16);
+impl_add_min!(i32);
+impl_add_min!(i64);
+impl_add_min!(i128);
+impl_add_min!(u8);
+impl_add_min!(u16);
+impl_add_min!(u32);
+impl_add_min!(u64);
+impl_add_min!(u128);
+
+impl_add_max!(i8);
+impl_add_max!(i16);
+impl_add_max!(i32);
+impl_add_max!(i64);
+impl_add_max!(i128);
+
+macro_rules! impl_sum {
+    ($t:ty, $zero:tt) => {
+        impl Magma for AddWithLenMagma<$t> {
+            type S = ($t, usize);
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                (lhs.0 + rhs.0, lhs.1 + rhs.1)
+            }
+        }
+
+        impl Monoid for AddWithLenMagma<$t> {
+            fn identity() -> Self::S {
+                ($zero, 0)
+            }
+        }
+
+        impl Magma for AssignMagma<$t> {
+            type S = Option<$t>;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                rhs.or(lhs)
+            }
+        }
+
+        impl Monoid for AssignMagma<$t> {
+            fn identity() -> Self::S {
+                None
+            }
+        }
+
+        impl Action for AssignSum<$t> {
+            type M = AddWithLenMagma<$t>;
+            type F = AssignMagma<$t>;
+            fn act(f: &Option<$t>, x: ($t, usize)) -> ($t, usize) {
+                match f {
+                    Some(a) => (*a * x.1 as $t, x.1),
+                    None => x,
+                }
+            }
+        }
+
+        impl Action for AffineSum<$t> {
+            type M = AddWithLenMagma<$t>;
+            type F = AffineMagma<$t>;
+            fn act(f: &($t, $t), x: ($t, usize)) -> ($t, usize) {
+                (f.0 * x.0 + f.1 * x.1 as $t, x.1)
+            }
+        }
+    };
+}
+
+impl_sum!(usize, 0);
+impl_sum!(i8, 0);
+impl_sum!(i16, 0);
+impl_sum!(i32, 0);
+impl_sum!(i64, 0);
+impl_sum!(i128, 0);
+impl_sum!(u8, 0);
+impl_sum!(u16, 0);
+impl_sum!(u32, 0);
+impl_sum!(u64, 0);
+impl_sum!(u128, 0);
+impl_sum!(f32, 0.0);
+impl_sum!(f64, 0.0);
+
+#[cfg(test)]
+mod test {
+    use magma::Magma;
+
+    use crate::{Action, AffineMagma, AffineSum, AssignSum};
+
+    #[test]
+    fn test_composition() {
+        let f = (2, 3);
+        let g = (-1, 5);
+        let h = AffineMagma::<i64>::binary_operation(f, g);
+        // apply f first, then g
+        assert_eq!(h, (-2, 2));
+        let x = (7, 2);
+        assert_eq!(
+            AffineSum::<i64>::act(&h, x),
+            AffineSum::<i64>::act(&g, AffineSum::<i64>::act(&f, x))
+        );
+        assert_eq!(AssignSum::<i64>::act(&Some(4), (10, 3)), (12, 3));
+        assert_eq!(AssignSum::<i64>::act(&None, (10, 3)), (10, 3));
+    }
+}
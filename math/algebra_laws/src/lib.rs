@@ -0,0 +1,570 @@
+use std::fmt::Debug;
+
+use action::Action;
+use group::Group;
+use magma::{Commutative, Idempotent, Magma};
+use monoid::Monoid;
+use semiring::Semiring;
+
+/// a violation of `law` by `elements`, where `lhs` and `rhs` are the two sides
+/// that should have been equal.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Counterexample<S> {
+    pub law: &'static str,
+    pub elements: Vec<S>,
+    pub lhs: S,
+    pub rhs: S,
+}
+
+pub type LawResult<S> = Result<(), Counterexample<S>>;
+
+fn check<S, const K: usize>(
+    law: &'static str,
+    samples: &[S],
+    sides: impl Fn([S; K]) -> (S, S),
+) -> LawResult<S>
+where
+    S: Clone + PartialEq,
+{
+    // enumerate the K-tuples in lexicographic order of indices.
+    let n = samples.len();
+    let mut idx = [0; K];
+    if n == 0 && K > 0 {
+        return Ok(());
+    }
+    loop {
+        let elements = idx.map(|i| samples[i].clone());
+        let (lhs, rhs) = sides(elements.clone());
+        if lhs != rhs {
+            return Err(Counterexample {
+                law,
+                elements: elements.to_vec(),
+                lhs,
+                rhs,
+            });
+        }
+        let Some(k) = (0..K).rev().find(|&k| idx[k] + 1 < n) else {
+            return Ok(());
+        };
+        idx[k] += 1;
+        idx[k + 1..].fill(0);
+    }
+}
+
+/// (x y) z = x (y z) for all x, y, z in `samples`.
+///
+/// every check in this crate tries the tuples in the order of `samples` and reports
+/// the first failure, so samples listed from small to large give a minimal counterexample.
+/// ```
+/// use algebra_laws::check_associativity;
+/// use magma::Magma;
+///
+/// struct Sub;
+/// impl Magma for Sub {
+///     type S = i32;
+///     fn binary_operation(lhs: i32, rhs: i32) -> i32 {
+///         lhs - rhs
+///     }
+/// }
+///
+/// let err = check_associativity::<Sub>(0..3).unwrap_err();
+/// assert_eq!(err.elements, [0, 0, 1]);
+/// assert_eq!((err.lhs, err.rhs), (-1, 1));
+/// ```
+pub fn check_associativity<M: Magma>(samples: impl IntoIterator<Item = M::S>) -> LawResult<M::S>
+where
+    M::S: Clone + PartialEq,
+{
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    check("associativity", &samples, |[x, y, z]| {
+        (
+            M::binary_operation(M::binary_operation(x.clone(), y.clone()), z.clone()),
+            M::binary_operation(x, M::binary_operation(y, z)),
+        )
+    })
+}
+
+/// x y = y x, as claimed by `Commutative`.
+/// an operation that is not declared commutative cannot be checked.
+/// ```compile_fail
+/// use algebra_laws::check_commutativity;
+/// use magma::MulMagma;
+/// use matrix::Matrix;
+/// use semiring::AddMul;
+///
+/// let _ = check_commutativity::<MulMagma<Matrix<AddMul<i64>>>>([]);
+/// ```
+pub fn check_commutativity<M: Magma + Commutative>(
+    samples: impl IntoIterator<Item = M::S>,
+) -> LawResult<M::S>
+where
+    M::S: Clone + PartialEq,
+{
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    check("commutativity", &samples, |[x, y]| {
+        (
+            M::binary_operation(x.clone(), y.clone()),
+            M::binary_operation(y, x),
+        )
+    })
+}
+
+/// x x = x, as claimed by `Idempotent`.
+pub fn check_idempotency<M: Magma + Idempotent>(
+    samples: impl IntoIterator<Item = M::S>,
+) -> LawResult<M::S>
+where
+    M::S: Clone + PartialEq,
+{
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    check("idempotency", &samples, |[x]| {
+        (M::binary_operation(x.clone(), x.clone()), x)
+    })
+}
+
+/// e x = x e = x for the identity e.
+pub fn check_identity<M: Monoid>(samples: impl IntoIterator<Item = M::S>) -> LawResult<M::S>
+where
+    M::S: Clone + PartialEq,
+{
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    check("left identity", &samples, |[x]| {
+        (M::binary_operation(M::identity(), x.clone()), x)
+    })?;
+    check("right identity", &samples, |[x]| {
+        (M::binary_operation(x.clone(), M::identity()), x)
+    })
+}
+
+/// x x^{-1} = x^{-1} x = e.
+pub fn check_inverse<M: Group>(samples: impl IntoIterator<Item = M::S>) -> LawResult<M::S>
+where
+    M::S: Clone + PartialEq,
+{
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    check("right inverse", &samples, |[x]| {
+        (M::binary_operation(x.clone(), M::inverse(x)), M::identity())
+    })?;
+    check("left inverse", &samples, |[x]| {
+        (M::binary_operation(M::inverse(x.clone()), x), M::identity())
+    })
+}
+
+/// associativity and identity.
+pub fn check_monoid<M: Monoid>(samples: impl IntoIterator<Item = M::S>) -> LawResult<M::S>
+where
+    M::S: Clone + PartialEq,
+{
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    check_associativity::<M>(samples.iter().cloned())?;
+    check_identity::<M>(samples)
+}
+
+/// associativity, identity and inverse.
+pub fn check_group<M: Group>(samples: impl IntoIterator<Item = M::S>) -> LawResult<M::S>
+where
+    M::S: Clone + PartialEq,
+{
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    check_monoid::<M>(samples.iter().cloned())?;
+    check_inverse::<M>(samples)
+}
+
+/// the laws stated on `Semiring`: both monoids, commutative addition,
+/// distributivity on both sides and the absorbing zero.
+pub fn check_semiring<R: Semiring>(samples: impl IntoIterator<Item = R::S>) -> LawResult<R::S>
+where
+    R::S: PartialEq,
+{
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    check_monoid::<R::Additive>(samples.iter().cloned())?;
+    check_commutativity::<R::Additive>(samples.iter().cloned())?;
+    check_monoid::<R::Multiplicative>(samples.iter().cloned())?;
+    check("left distributivity", &samples, |[x, y, z]| {
+        (
+            R::mul(x.clone(), R::add(y.clone(), z.clone())),
+            R::add(R::mul(x.clone(), y), R::mul(x, z)),
+        )
+    })?;
+    check("right distributivity", &samples, |[x, y, z]| {
+        (
+            R::mul(R::add(x.clone(), y.clone()), z.clone()),
+            R::add(R::mul(x, z.clone()), R::mul(y, z)),
+        )
+    })?;
+    check("absorbing zero", &samples, |[x]| {
+        (R::mul(R::zero(), x.clone()), R::zero())
+    })?;
+    check("absorbing zero", &samples, |[x]| {
+        (R::mul(x, R::zero()), R::zero())
+    })
+}
+
+/// (map, element) of the action `A`.
+pub type ActionSample<A> = (
+    <<A as Action>::F as Magma>::S,
+    <<A as Action>::M as Magma>::S,
+);
+
+/// the laws stated on `Action`: the identity map acts trivially, `act(f, _)` is a monoid
+/// homomorphism and `act(f g, x) = act(g, act(f, x))`.
+/// every element and side of a counterexample is paired with the map applied to it.
+pub fn check_action<A: Action>(
+    maps: impl IntoIterator<Item = <A::F as Magma>::S>,
+    samples: impl IntoIterator<Item = <A::M as Magma>::S>,
+) -> LawResult<ActionSample<A>>
+where
+    <A::F as Magma>::S: Clone,
+    <A::M as Magma>::S: Clone + PartialEq,
+{
+    let maps = maps.into_iter().collect::<Vec<_>>();
+    let samples = samples.into_iter().collect::<Vec<_>>();
+    let id = A::F::identity();
+    with_map(
+        &id,
+        check("identity map", &samples, |[x]| (A::act(&id, x.clone()), x)),
+    )?;
+    for f in &maps {
+        with_map(
+            f,
+            check("homomorphism", &[A::M::identity()], |[e]| {
+                (A::act(f, e.clone()), e)
+            }),
+        )?;
+        with_map(
+            f,
+            check("homomorphism", &samples, |[x, y]| {
+                (
+                    A::act(f, A::M::binary_operation(x.clone(), y.clone())),
+                    A::M::binary_operation(A::act(f, x), A::act(f, y)),
+                )
+            }),
+        )?;
+    }
+    for f in &maps {
+        for g in &maps {
+            let fg = A::F::binary_operation(f.clone(), g.clone());
+            with_map(
+                &fg,
+                check("composition", &samples, |[x]| {
+                    (A::act(&fg, x.clone()), A::act(g, A::act(f, x)))
+                }),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn with_map<F: Clone, S>(f: &F, result: LawResult<S>) -> LawResult<(F, S)> {
+    result.map_err(|e| Counterexample {
+        law: e.law,
+        elements: e.elements.into_iter().map(|x| (f.clone(), x)).collect(),
+        lhs: (f.clone(), e.lhs),
+        rhs: (f.clone(), e.rhs),
+    })
+}
+
+/// panic with the counterexample if `result` is an error.
+pub fn assert_law<S: Debug>(result: LawResult<S>) {
+    if let Err(e) = result {
+        panic!(
+            "{} fails for {:?}: {:?} != {:?}",
+            e.law, e.elements, e.lhs, e.rhs
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::num::{Saturating, Wrapping};
+
+    use action::{
+        define_action, AddMax, AddMin, AddWithLenMagma, AffineSum, AssignMagma, AssignSum,
+    };
+    use magma::{
+        AddMagma, Affine, AndMagma, ArgMax, ArgMin, CheckedAddMagma, GcdMagma, LcmMagma, Magma,
+        MaxMagma, MinMagma, MulMagma, OptionMonoid, OrMagma, Pair, Rev, SaturatingAddMagma,
+        WrappingAddMagma, XorMagma,
+    };
+    use matrix::{MatMul, Matrix};
+    use modint::{ModInt998244353, StaticModInt};
+    use monoid::Monoid;
+    use ord_float::{TotalF32, TotalF64};
+    use rational::Rational;
+    use semiring::{AddMul, MaxPlus, MinPlus, MinPlusMulMagma};
+
+    use crate::{
+        assert_law, check_action, check_associativity, check_commutativity, check_group,
+        check_idempotency, check_identity, check_monoid, check_semiring, Counterexample,
+    };
+
+    macro_rules! check_integer {
+        ($t:ty, $small:expr) => {{
+            let small = ($small).collect::<Vec<$t>>();
+            let wide = small
+                .iter()
+                .copied()
+                .chain([<$t>::MIN, <$t>::MAX])
+                .collect::<Vec<_>>();
+            let indexed = wide
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| (x, i % 3))
+                .collect::<Vec<_>>();
+
+            assert_law(check_monoid::<AddMagma<$t>>(small.clone()));
+            assert_law(check_commutativity::<AddMagma<$t>>(small.clone()));
+            assert_law(check_monoid::<MulMagma<$t>>(small.clone()));
+            assert_law(check_commutativity::<MulMagma<$t>>(small.clone()));
+
+            assert_law(check_monoid::<MaxMagma<$t>>(wide.clone()));
+            assert_law(check_commutativity::<MaxMagma<$t>>(wide.clone()));
+            assert_law(check_idempotency::<MaxMagma<$t>>(wide.clone()));
+            assert_law(check_monoid::<MinMagma<$t>>(wide.clone()));
+            assert_law(check_commutativity::<MinMagma<$t>>(wide.clone()));
+            assert_law(check_idempotency::<MinMagma<$t>>(wide.clone()));
+
+            assert_law(check_group::<XorMagma<$t>>(wide.clone()));
+            assert_law(check_commutativity::<XorMagma<$t>>(wide.clone()));
+            assert_law(check_monoid::<AndMagma<$t>>(wide.clone()));
+            assert_law(check_commutativity::<AndMagma<$t>>(wide.clone()));
+            assert_law(check_idempotency::<AndMagma<$t>>(wide.clone()));
+            assert_law(check_monoid::<OrMagma<$t>>(wide.clone()));
+            assert_law(check_commutativity::<OrMagma<$t>>(wide.clone()));
+            assert_law(check_idempotency::<OrMagma<$t>>(wide.clone()));
+
+            assert_law(check_monoid::<ArgMin<$t>>(indexed.clone()));
+            assert_law(check_commutativity::<ArgMin<$t>>(indexed.clone()));
+            assert_law(check_idempotency::<ArgMin<$t>>(indexed.clone()));
+            assert_law(check_monoid::<ArgMax<$t>>(indexed.clone()));
+            assert_law(check_commutativity::<ArgMax<$t>>(indexed.clone()));
+            assert_law(check_idempotency::<ArgMax<$t>>(indexed.clone()));
+
+            assert_law(check_group::<WrappingAddMagma<$t>>(wide.clone()));
+            assert_law(check_commutativity::<WrappingAddMagma<$t>>(wide.clone()));
+            let wrapping = wide.iter().map(|&x| Wrapping(x));
+            assert_law(check_monoid::<AddMagma<Wrapping<$t>>>(wrapping.clone()));
+            assert_law(check_monoid::<MulMagma<Wrapping<$t>>>(wrapping));
+
+            assert_law(check_semiring::<AddMul<$t>>(small.clone()));
+            let min_plus = small.iter().copied().chain([<$t>::MAX]);
+            assert_law(check_commutativity::<MinPlusMulMagma<$t>>(min_plus.clone()));
+            assert_law(check_semiring::<MinPlus<$t>>(min_plus));
+
+            let with_len = small.iter().map(|&x| (x, x as usize % 3));
+            assert_law(check_monoid::<AddWithLenMagma<$t>>(with_len.clone()));
+            let assign = small.iter().map(|&x| Some(x)).chain([None]);
+            assert_law(check_monoid::<AssignMagma<$t>>(assign.clone()));
+
+            let add_min = small.iter().copied().chain([<$t>::MAX]);
+            assert_law(check_action::<AddMin<$t>>(small.clone(), add_min));
+            assert_law(check_action::<AssignSum<$t>>(assign, with_len.clone()));
+            // small maps, since composition multiplies the coefficients.
+            let affine = [(1, 0), (0, 1), (2, 1), (1, 2)];
+            assert_law(check_action::<AffineSum<$t>>(affine, with_len));
+        }};
+        ($t:ty, $small:expr, unsigned) => {{
+            check_integer!($t, $small);
+            let small = ($small).collect::<Vec<$t>>();
+            let wide = small
+                .iter()
+                .copied()
+                .chain([<$t>::MAX - 1, <$t>::MAX])
+                .collect::<Vec<_>>();
+            assert_law(check_monoid::<GcdMagma<$t>>(wide.clone()));
+            assert_law(check_commutativity::<GcdMagma<$t>>(wide.clone()));
+            assert_law(check_idempotency::<GcdMagma<$t>>(wide.clone()));
+            // lcm overflows on larger values.
+            assert_law(check_monoid::<LcmMagma<$t>>(small.clone()));
+            assert_law(check_commutativity::<LcmMagma<$t>>(small.clone()));
+            assert_law(check_idempotency::<LcmMagma<$t>>(small));
+
+            assert_law(check_monoid::<SaturatingAddMagma<$t>>(wide.clone()));
+            assert_law(check_commutativity::<SaturatingAddMagma<$t>>(wide.clone()));
+            let checked = wide.iter().map(|&x| Some(x)).chain([None]);
+            assert_law(check_monoid::<CheckedAddMagma<$t>>(checked.clone()));
+            assert_law(check_commutativity::<CheckedAddMagma<$t>>(checked));
+            let saturating = wide.iter().map(|&x| Saturating(x));
+            assert_law(check_monoid::<AddMagma<Saturating<$t>>>(saturating.clone()));
+            assert_law(check_monoid::<MulMagma<Saturating<$t>>>(saturating));
+        }};
+        ($t:ty, $small:expr, signed) => {{
+            check_integer!($t, $small);
+            let small = ($small).collect::<Vec<$t>>();
+            assert_law(check_group::<AddMagma<$t>>(small.clone()));
+            let wrapping = [<$t>::MIN, -1, 0, 1, <$t>::MAX].map(Wrapping);
+            assert_law(check_group::<AddMagma<Wrapping<$t>>>(wrapping));
+            let max_plus = small.iter().copied().chain([<$t>::MIN]);
+            assert_law(check_semiring::<MaxPlus<$t>>(max_plus));
+            let add_max = small.iter().copied().chain([<$t>::MIN]);
+            assert_law(check_action::<AddMax<$t>>(small.clone(), add_max));
+            // gcd and lcm of signed values are semigroups, as gcd(0, x) = |x|.
+            let gcd = small.iter().copied().chain([<$t>::MIN + 1, <$t>::MAX]);
+            assert_law(check_associativity::<GcdMagma<$t>>(gcd.clone()));
+            assert_law(check_commutativity::<GcdMagma<$t>>(gcd));
+            assert_law(check_associativity::<LcmMagma<$t>>(small.clone()));
+            assert_law(check_commutativity::<LcmMagma<$t>>(small));
+        }};
+    }
+
+    #[test]
+    fn test_integers() {
+        check_integer!(usize, 0..=6, unsigned);
+        check_integer!(u8, 0..=6, unsigned);
+        check_integer!(u16, 0..=6, unsigned);
+        check_integer!(u32, 0..=6, unsigned);
+        check_integer!(u64, 0..=6, unsigned);
+        check_integer!(u128, 0..=6, unsigned);
+        check_integer!(i8, -3..=3, signed);
+        check_integer!(i16, -3..=3, signed);
+        check_integer!(i32, -3..=3, signed);
+        check_integer!(i64, -3..=3, signed);
+        check_integer!(i128, -3..=3, signed);
+    }
+
+    #[test]
+    fn test_fields() {
+        type F = StaticModInt<7>;
+        assert_law(check_group::<AddMagma<F>>((0..7).map(F::new)));
+        assert_law(check_group::<MulMagma<F>>((1..7).map(F::new)));
+        assert_law(check_semiring::<AddMul<F>>((0..7).map(F::new)));
+
+        type Mint = ModInt998244353;
+        let mint = [0, 1, 2, 998244352, 123456789].map(Mint::new);
+        assert_law(check_group::<AddMagma<Mint>>(mint));
+        assert_law(check_group::<MulMagma<Mint>>(mint[1..].to_vec()));
+
+        type Q = Rational<i64>;
+        let q = [(0, 1), (1, 1), (-1, 2), (3, 4), (-5, 3)].map(|(n, d)| Q::new(n, d));
+        assert_law(check_group::<AddMagma<Q>>(q));
+        assert_law(check_group::<MulMagma<Q>>(q[1..].to_vec()));
+        assert_law(check_semiring::<AddMul<Q>>(q));
+    }
+
+    #[test]
+    fn test_ord_float() {
+        let v = [f64::NEG_INFINITY, -2.5, -0.0, 0.0, 0.75, 4.0, f64::INFINITY].map(TotalF64::new);
+        assert_law(check_monoid::<MaxMagma<TotalF64>>(v));
+        assert_law(check_idempotency::<MaxMagma<TotalF64>>(v));
+        assert_law(check_monoid::<MinMagma<TotalF64>>(v));
+        assert_law(check_commutativity::<MinMagma<TotalF64>>(v));
+        let indexed = v.iter().enumerate().map(|(i, &x)| (x, i % 2));
+        assert_law(check_monoid::<ArgMax<TotalF64>>(indexed));
+        // dyadic values whose sums are exact.
+        let exact = [-2.5, -0.0, 0.0, 0.75, 4.0].map(TotalF64::new);
+        assert_law(check_group::<AddMagma<TotalF64>>(exact));
+        assert_law(check_group::<MulMagma<TotalF64>>(exact[3..].to_vec()));
+        let w = [f32::NEG_INFINITY, -1.0, 0.5, f32::INFINITY].map(TotalF32::new);
+        assert_law(check_monoid::<MinMagma<TotalF32>>(w));
+    }
+
+    #[test]
+    fn test_combinators() {
+        let pairs = [(0, 0), (1, 5), (-2, 3), (3, 255)];
+        assert_law(check_group::<Pair<AddMagma<i32>, XorMagma<u8>>>(pairs));
+        assert_law(check_commutativity::<Pair<AddMagma<i32>, XorMagma<u8>>>(
+            pairs,
+        ));
+
+        let maps = [(1, 0), (2, 1), (-1, 3), (0, 2), (3, -2)];
+        assert_law(check_monoid::<Affine<i64>>(maps));
+        assert_law(check_monoid::<Rev<Affine<i64>>>(maps));
+        let q = |a: i64, b: i64| (Rational::from(a), Rational::from(b));
+        let rational_maps = [q(1, 0), q(2, 1), q(-1, 3), q(3, -2)];
+        assert_law(check_group::<Affine<Rational<i64>>>(rational_maps));
+        assert_law(check_group::<Rev<Affine<Rational<i64>>>>(rational_maps));
+
+        let options = [None, Some((2, 1)), Some((-1, 3)), Some((0, 2))];
+        assert_law(check_monoid::<OptionMonoid<Affine<i64>>>(options));
+        assert_law(check_monoid::<OptionMonoid<MaxMagma<i32>>>([
+            None,
+            Some(1),
+            Some(-4),
+        ]));
+
+        let m = |v: [[i64; 2]; 2]| Matrix::<AddMul<i64>>::from_vec(v.map(Vec::from).to_vec());
+        let matrices = [
+            m([[1, 2], [3, 4]]),
+            m([[0, 1], [1, 0]]),
+            m([[2, -1], [0, 3]]),
+        ];
+        assert_law(check_monoid::<MatMul<AddMul<i64>, 2>>(matrices.clone()));
+        // matrix multiplication is associative, but not commutative.
+        type MatrixMul = MulMagma<Matrix<AddMul<i64>>>;
+        assert_law(check_associativity::<MatrixMul>(matrices.clone()));
+        let [a, b, _] = matrices;
+        assert_ne!(
+            MatrixMul::binary_operation(a.clone(), b.clone()),
+            MatrixMul::binary_operation(b, a)
+        );
+        let t = |v: [[i64; 2]; 2]| Matrix::<MinPlus<i64>>::from_vec(v.map(Vec::from).to_vec());
+        let inf = i64::MAX;
+        let tropical = [t([[0, 2], [inf, 1]]), t([[3, inf], [-1, 0]])];
+        assert_law(check_monoid::<MatMul<MinPlus<i64>, 2>>(tropical));
+    }
+
+    struct Sub;
+    impl Magma for Sub {
+        type S = i32;
+        fn binary_operation(lhs: i32, rhs: i32) -> i32 {
+            lhs - rhs
+        }
+    }
+    impl Monoid for Sub {
+        fn identity() -> i32 {
+            0
+        }
+    }
+
+    #[test]
+    fn test_counterexample() {
+        assert_eq!(
+            check_associativity::<Sub>(0..5),
+            Err(Counterexample {
+                law: "associativity",
+                elements: vec![0, 0, 1],
+                lhs: -1,
+                rhs: 1,
+            })
+        );
+        assert_eq!(
+            check_identity::<Sub>([0, 3, 5]),
+            Err(Counterexample {
+                law: "left identity",
+                elements: vec![3],
+                lhs: -3,
+                rhs: 3,
+            })
+        );
+        assert_eq!(check_associativity::<Sub>([]), Ok(()));
+        assert_eq!(check_identity::<Sub>([0]), Ok(()));
+    }
+
+    define_action! {
+        /// adds `f` to the sum without the length, so the empty sum is moved.
+        ShiftSum,
+        monoid: AddMagma<i64>,
+        map: AddMagma<i64>,
+        act: |&f, x| x + f,
+    }
+
+    #[test]
+    fn test_action_counterexample() {
+        assert_eq!(
+            check_action::<ShiftSum>([1, 2], [0, 3]),
+            Err(Counterexample {
+                law: "homomorphism",
+                elements: vec![(1, 0)],
+                lhs: (1, 1),
+                rhs: (1, 0),
+            })
+        );
+        assert_eq!(check_action::<ShiftSum>([0], [0, 3]), Ok(()));
+    }
+
+    #[test]
+    #[should_panic(expected = "associativity fails for [0, 0, 1]: -1 != 1")]
+    fn test_assert_law() {
+        assert_law(check_monoid::<Sub>(0..3));
+    }
+}
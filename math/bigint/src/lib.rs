@@ -0,0 +1,816 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+use convolution::convolution_u64;
+use magma::{CommutativeAdd, CommutativeMul};
+use one::One;
+use zero::Zero;
+
+// magnitudes are little endian vectors of limbs in base 10^9 without leading zeros.
+const BASE: u64 = 1_000_000_000;
+const WIDTH: usize = 9;
+const KARATSUBA_THRESHOLD: usize = 32;
+const NTT_THRESHOLD: usize = 512;
+
+fn trim(a: &mut Vec<u32>) {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+fn cmp_limbs(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (a, b) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut res = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let s = x + b.get(i).copied().unwrap_or(0) + carry;
+        carry = (s >= BASE as u32) as u32;
+        res.push(s - carry * BASE as u32);
+    }
+    if carry > 0 {
+        res.push(carry);
+    }
+    res
+}
+
+/// a - b for a >= b.
+fn sub_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut res = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &x) in a.iter().enumerate() {
+        let d = x as i64 - b.get(i).copied().unwrap_or(0) as i64 - borrow;
+        borrow = (d < 0) as i64;
+        res.push((d + borrow * BASE as i64) as u32);
+    }
+    assert_eq!(borrow, 0);
+    trim(&mut res);
+    res
+}
+
+/// res\[k..\] += z. `res` must be long enough to hold the result.
+fn add_at(res: &mut [u32], z: &[u32], k: usize) {
+    let mut carry = 0;
+    let mut i = 0;
+    while i < z.len() || carry > 0 {
+        let s = res[k + i] + z.get(i).copied().unwrap_or(0) + carry;
+        carry = (s >= BASE as u32) as u32;
+        res[k + i] = s - carry * BASE as u32;
+        i += 1;
+    }
+}
+
+fn mul_small(a: &[u32], k: u32) -> Vec<u32> {
+    let mut res = Vec::with_capacity(a.len() + 1);
+    let mut carry = 0;
+    for &x in a {
+        let p = x as u64 * k as u64 + carry;
+        res.push((p % BASE) as u32);
+        carry = p / BASE;
+    }
+    if carry > 0 {
+        res.push(carry as u32);
+    }
+    trim(&mut res);
+    res
+}
+
+fn mul_school(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let p = res[i + j] + x as u64 * y as u64 + carry;
+            res[i + j] = p % BASE;
+            carry = p / BASE;
+        }
+        res[i + b.len()] = carry;
+    }
+    let mut res = res.into_iter().map(|x| x as u32).collect();
+    trim(&mut res);
+    res
+}
+
+fn mul_karatsuba(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return mul_school(a, b);
+    }
+    let m = a.len().max(b.len()) / 2;
+    let split = |x: &[u32]| {
+        let mut lo = x[..m.min(x.len())].to_vec();
+        trim(&mut lo);
+        (lo, x.get(m..).unwrap_or(&[]).to_vec())
+    };
+    let (a0, a1) = split(a);
+    let (b0, b1) = split(b);
+    let z0 = mul_karatsuba(&a0, &b0);
+    let z2 = mul_karatsuba(&a1, &b1);
+    let z1 = mul_karatsuba(&add_limbs(&a0, &a1), &add_limbs(&b0, &b1));
+    let z1 = sub_limbs(&sub_limbs(&z1, &z0), &z2);
+    let mut res = vec![0; a.len() + b.len() + 1];
+    add_at(&mut res, &z0, 0);
+    add_at(&mut res, &z1, m);
+    add_at(&mut res, &z2, 2 * m);
+    trim(&mut res);
+    res
+}
+
+fn mul_ntt(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let a = a.iter().map(|&x| x as u64).collect::<Vec<_>>();
+    let b = b.iter().map(|&x| x as u64).collect::<Vec<_>>();
+    let mut res = Vec::with_capacity(a.len() + b.len());
+    let mut carry = 0u128;
+    for x in convolution_u64(&a, &b) {
+        let s = x + carry;
+        res.push((s % BASE as u128) as u32);
+        carry = s / BASE as u128;
+    }
+    while carry > 0 {
+        res.push((carry % BASE as u128) as u32);
+        carry /= BASE as u128;
+    }
+    trim(&mut res);
+    res
+}
+
+fn mul_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.len().min(b.len()) >= NTT_THRESHOLD {
+        mul_ntt(a, b)
+    } else {
+        mul_karatsuba(a, b)
+    }
+}
+
+/// `d` must not be zero.
+fn div_rem_small(a: &[u32], d: u32) -> (Vec<u32>, u32) {
+    assert!(d != 0, "division by zero");
+    let mut q = vec![0; a.len()];
+    let mut r = 0;
+    for i in (0..a.len()).rev() {
+        let cur = r * BASE + a[i] as u64;
+        q[i] = (cur / d as u64) as u32;
+        r = cur % d as u64;
+    }
+    trim(&mut q);
+    (q, r as u32)
+}
+
+/// long division by Knuth's Algorithm D.
+fn div_rem_limbs(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    assert!(!b.is_empty(), "division by zero");
+    if cmp_limbs(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+    if b.len() == 1 {
+        let (q, r) = div_rem_small(a, b[0]);
+        return (q, if r == 0 { vec![] } else { vec![r] });
+    }
+    // normalize so that the top limb of the divisor is at least BASE / 2.
+    let norm = (BASE / (*b.last().unwrap() as u64 + 1)) as u32;
+    let mut u = mul_small(a, norm);
+    u.resize(a.len() + 1, 0);
+    let v = mul_small(b, norm);
+    let n = v.len();
+    let m = a.len() - n;
+    let (v1, v2) = (v[n - 1] as u64, v[n - 2] as u64);
+    let mut q = vec![0; m + 1];
+    for j in (0..=m).rev() {
+        let num = u[j + n] as u64 * BASE + u[j + n - 1] as u64;
+        let mut qhat = num / v1;
+        let mut rhat = num % v1;
+        while qhat >= BASE || qhat * v2 > rhat * BASE + u[j + n - 2] as u64 {
+            qhat -= 1;
+            rhat += v1;
+            if rhat >= BASE {
+                break;
+            }
+        }
+        let mut borrow = 0;
+        let mut carry = 0;
+        for i in 0..n {
+            let p = qhat * v[i] as u64 + carry;
+            carry = p / BASE;
+            let d = u[i + j] as i64 - (p % BASE) as i64 - borrow;
+            borrow = (d < 0) as i64;
+            u[i + j] = (d + borrow * BASE as i64) as u32;
+        }
+        let d = u[j + n] as i64 - carry as i64 - borrow;
+        if d < 0 {
+            // qhat was one too large: add the divisor back.
+            qhat -= 1;
+            u[j + n] = (d + BASE as i64) as u32;
+            let mut carry = 0;
+            for i in 0..n {
+                let s = u[i + j] as u64 + v[i] as u64 + carry;
+                u[i + j] = (s % BASE) as u32;
+                carry = s / BASE;
+            }
+            u[j + n] = ((u[j + n] as u64 + carry) % BASE) as u32;
+        } else {
+            u[j + n] = d as u32;
+        }
+        q[j] = qhat as u32;
+    }
+    trim(&mut q);
+    u.truncate(n);
+    trim(&mut u);
+    (q, div_rem_small(&u, norm).0)
+}
+
+fn parse_limbs(digits: &str) -> Vec<u32> {
+    let bytes = digits.as_bytes();
+    let mut res = bytes
+        .rchunks(WIDTH)
+        .map(|chunk| chunk.iter().fold(0, |acc, &c| acc * 10 + (c - b'0') as u32))
+        .collect();
+    trim(&mut res);
+    res
+}
+
+/// # BigUint
+/// arbitrary-precision nonnegative integer.
+/// multiplication is Karatsuba, or NTT for very long operands.
+/// ```
+/// use bigint::BigUint;
+///
+/// let a: BigUint = "12345678901234567890123456789".parse().unwrap();
+/// let b: BigUint = "98765432109876543210".parse().unwrap();
+/// assert_eq!((&a * &b).to_string(), "1219326311370217952249657064223746380111126352690");
+/// assert_eq!((&a / &b).to_string(), "124999998");
+/// assert_eq!((&a % &b).to_string(), "85030864208626543209");
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// (self / rhs, self % rhs). `rhs` must not be zero.
+    pub fn div_rem(&self, rhs: &BigUint) -> (BigUint, BigUint) {
+        let (q, r) = div_rem_limbs(&self.limbs, &rhs.limbs);
+        (BigUint { limbs: q }, BigUint { limbs: r })
+    }
+
+    /// (self / rhs, self % rhs) for a small divisor in O(n).
+    pub fn div_rem_small(&self, rhs: u32) -> (BigUint, u32) {
+        let (q, r) = div_rem_small(&self.limbs, rhs);
+        (BigUint { limbs: q }, r)
+    }
+
+    pub fn pow(&self, n: u32) -> BigUint {
+        let mut res = BigUint::one();
+        let mut x = self.clone();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                res = &res * &x;
+            }
+            n >>= 1;
+            if n > 0 {
+                x = &x * &x;
+            }
+        }
+        res
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigUint {
+                fn from(x: $t) -> Self {
+                    let mut x = x as u128;
+                    let mut limbs = vec![];
+                    while x > 0 {
+                        limbs.push((x % BASE as u128) as u32);
+                        x /= BASE as u128;
+                    }
+                    BigUint { limbs }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned!(usize, u8, u16, u32, u64, u128);
+
+/// error of parsing a string as a decimal integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseBigIntError {
+    /// no digits after the optional sign.
+    Empty,
+    /// a character other than an ASCII digit after the optional sign.
+    InvalidDigit,
+}
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseBigIntError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseBigIntError::InvalidDigit => write!(f, "invalid digit found in string"),
+        }
+    }
+}
+
+impl std::error::Error for ParseBigIntError {}
+
+fn check_digits(digits: &str) -> Result<(), ParseBigIntError> {
+    if digits.is_empty() {
+        Err(ParseBigIntError::Empty)
+    } else if !digits.bytes().all(|c| c.is_ascii_digit()) {
+        Err(ParseBigIntError::InvalidDigit)
+    } else {
+        Ok(())
+    }
+}
+
+impl FromStr for BigUint {
+    type Err = ParseBigIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.strip_prefix('+').unwrap_or(s);
+        check_digits(digits)?;
+        Ok(BigUint {
+            limbs: parse_limbs(digits),
+        })
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.limbs.split_last() {
+            None => write!(f, "0"),
+            Some((top, rest)) => {
+                write!(f, "{}", top)?;
+                for x in rest.iter().rev() {
+                    write!(f, "{:09}", x)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl Debug for BigUint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_limbs(&self.limbs, &other.limbs)
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn add(self, rhs: &BigUint) -> BigUint {
+        BigUint {
+            limbs: add_limbs(&self.limbs, &rhs.limbs),
+        }
+    }
+}
+
+impl Sub<&BigUint> for &BigUint {
+    type Output = BigUint;
+    /// panics if `rhs` is greater than `self`.
+    fn sub(self, rhs: &BigUint) -> BigUint {
+        assert!(*self >= *rhs, "subtraction underflow");
+        BigUint {
+            limbs: sub_limbs(&self.limbs, &rhs.limbs),
+        }
+    }
+}
+
+impl Mul<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        BigUint {
+            limbs: mul_limbs(&self.limbs, &rhs.limbs),
+        }
+    }
+}
+
+impl Div<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn div(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigUint> for &BigUint {
+    type Output = BigUint;
+    fn rem(self, rhs: &BigUint) -> BigUint {
+        self.div_rem(rhs).1
+    }
+}
+
+/// # BigInt
+/// arbitrary-precision signed integer. division truncates toward zero like the primitive types.
+/// ```
+/// use bigint::BigInt;
+///
+/// let a: BigInt = "-12345678901234567890123456789".parse().unwrap();
+/// let b: BigInt = "98765432109876543210".parse().unwrap();
+/// assert_eq!((&a / &b).to_string(), "-124999998");
+/// assert_eq!((&a % &b).to_string(), "-85030864208626543209");
+/// assert_eq!(&a + &(-&a), BigInt::from(0));
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    /// always false for zero.
+    neg: bool,
+    abs: BigUint,
+}
+
+impl BigInt {
+    fn from_parts(neg: bool, abs: BigUint) -> Self {
+        BigInt {
+            neg: neg && !abs.is_zero(),
+            abs,
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.abs.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.neg
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.abs
+    }
+
+    pub fn abs(&self) -> BigInt {
+        BigInt::from(self.abs.clone())
+    }
+
+    /// (self / rhs, self % rhs), rounding toward zero. `rhs` must not be zero.
+    pub fn div_rem(&self, rhs: &BigInt) -> (BigInt, BigInt) {
+        let (q, r) = self.abs.div_rem(&rhs.abs);
+        (
+            BigInt::from_parts(self.neg != rhs.neg, q),
+            BigInt::from_parts(self.neg, r),
+        )
+    }
+
+    pub fn pow(&self, n: u32) -> BigInt {
+        BigInt::from_parts(self.neg && n % 2 == 1, self.abs.pow(n))
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(abs: BigUint) -> Self {
+        BigInt { neg: false, abs }
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(x: $t) -> Self {
+                    BigInt::from_parts(x < 0, BigUint::from(x.unsigned_abs()))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_signed!(isize, i8, i16, i32, i64, i128);
+
+macro_rules! impl_from_unsigned_signed {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for BigInt {
+                fn from(x: $t) -> Self {
+                    BigInt::from(BigUint::from(x))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_unsigned_signed!(usize, u8, u16, u32, u64, u128);
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (neg, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+        check_digits(digits)?;
+        Ok(BigInt::from_parts(
+            neg,
+            BigUint {
+                limbs: parse_limbs(digits),
+            },
+        ))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.neg {
+            write!(f, "-")?;
+        }
+        Display::fmt(&self.abs, f)
+    }
+}
+
+impl Debug for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.neg, other.neg) {
+            (false, false) => self.abs.cmp(&other.abs),
+            (true, true) => other.abs.cmp(&self.abs),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn add(self, rhs: &BigInt) -> BigInt {
+        if self.neg == rhs.neg {
+            return BigInt::from_parts(self.neg, &self.abs + &rhs.abs);
+        }
+        match self.abs.cmp(&rhs.abs) {
+            Ordering::Less => BigInt::from_parts(rhs.neg, &rhs.abs - &self.abs),
+            _ => BigInt::from_parts(self.neg, &self.abs - &rhs.abs),
+        }
+    }
+}
+
+impl Sub<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn sub(self, rhs: &BigInt) -> BigInt {
+        self.add(&-rhs)
+    }
+}
+
+impl Mul<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn mul(self, rhs: &BigInt) -> BigInt {
+        BigInt::from_parts(self.neg != rhs.neg, &self.abs * &rhs.abs)
+    }
+}
+
+impl Div<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn div(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).0
+    }
+}
+
+impl Rem<&BigInt> for &BigInt {
+    type Output = BigInt;
+    fn rem(self, rhs: &BigInt) -> BigInt {
+        self.div_rem(rhs).1
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.neg, self.abs.clone())
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+    fn neg(self) -> BigInt {
+        BigInt::from_parts(!self.neg, self.abs)
+    }
+}
+
+/// the remaining combinations of owned and borrowed operands, and the assign operators.
+macro_rules! forward_binop {
+    ($t:ty, $tr:ident, $f:ident, $tr_assign:ident, $f_assign:ident) => {
+        impl $tr<$t> for $t {
+            type Output = $t;
+            fn $f(self, rhs: $t) -> $t {
+                $tr::$f(&self, &rhs)
+            }
+        }
+
+        impl $tr<&$t> for $t {
+            type Output = $t;
+            fn $f(self, rhs: &$t) -> $t {
+                $tr::$f(&self, rhs)
+            }
+        }
+
+        impl $tr<$t> for &$t {
+            type Output = $t;
+            fn $f(self, rhs: $t) -> $t {
+                $tr::$f(self, &rhs)
+            }
+        }
+
+        impl $tr_assign<$t> for $t {
+            fn $f_assign(&mut self, rhs: $t) {
+                *self = $tr::$f(&*self, &rhs);
+            }
+        }
+
+        impl $tr_assign<&$t> for $t {
+            fn $f_assign(&mut self, rhs: &$t) {
+                *self = $tr::$f(&*self, rhs);
+            }
+        }
+    };
+}
+
+macro_rules! impl_common {
+    ($t:ty) => {
+        forward_binop!($t, Add, add, AddAssign, add_assign);
+        forward_binop!($t, Sub, sub, SubAssign, sub_assign);
+        forward_binop!($t, Mul, mul, MulAssign, mul_assign);
+        forward_binop!($t, Div, div, DivAssign, div_assign);
+        forward_binop!($t, Rem, rem, RemAssign, rem_assign);
+
+        impl Zero for $t {
+            fn zero() -> Self {
+                Self::default()
+            }
+        }
+
+        impl One for $t {
+            fn one() -> Self {
+                Self::from(1u32)
+            }
+        }
+
+        impl CommutativeAdd for $t {}
+        impl CommutativeMul for $t {}
+
+        impl Sum for $t {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl Product for $t {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * x)
+            }
+        }
+    };
+}
+
+impl_common!(BigUint);
+impl_common!(BigInt);
+
+#[cfg(test)]
+mod test {
+    use graph_base::{DirectedGraph, GraphBase};
+    use shortest_path::ShortestPath;
+
+    use crate::{mul_karatsuba, mul_ntt, mul_school, BigInt, BigUint, ParseBigIntError, BASE};
+
+    /// deterministic pseudo random sequence.
+    fn sequence(n: usize, seed: u64) -> Vec<u64> {
+        let mut x = seed;
+        (0..n)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                x
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_small() {
+        let values = sequence(60, 1)
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| {
+                let v = (x as i128) >> (i % 4 * 20);
+                if i % 2 == 0 {
+                    v
+                } else {
+                    -v
+                }
+            })
+            .chain([0, 1, -1, BASE as i128, -(BASE as i128)])
+            .collect::<Vec<_>>();
+        for &x in &values {
+            let a = BigInt::from(x);
+            assert_eq!(a.to_string(), x.to_string());
+            assert_eq!(x.to_string().parse::<BigInt>().unwrap(), a);
+            for &y in &values {
+                let b = BigInt::from(y);
+                assert_eq!(&a + &b, BigInt::from(x + y));
+                assert_eq!(&a - &b, BigInt::from(x - y));
+                assert_eq!(a.cmp(&b), x.cmp(&y));
+                if let Some(p) = x.checked_mul(y) {
+                    assert_eq!(&a * &b, BigInt::from(p));
+                }
+                if y != 0 {
+                    assert_eq!(&a / &b, BigInt::from(x / y));
+                    assert_eq!(&a % &b, BigInt::from(x % y));
+                }
+            }
+        }
+        assert_eq!("".parse::<BigUint>(), Err(ParseBigIntError::Empty));
+        assert_eq!("-".parse::<BigInt>(), Err(ParseBigIntError::Empty));
+        assert_eq!("-1".parse::<BigUint>(), Err(ParseBigIntError::InvalidDigit));
+        assert_eq!("1a".parse::<BigInt>(), Err(ParseBigIntError::InvalidDigit));
+        assert_eq!("-0".parse::<BigInt>().unwrap(), BigInt::from(0));
+        assert_eq!("000123".parse::<BigUint>().unwrap().to_string(), "123");
+    }
+
+    fn random(n: usize, seed: u64) -> Vec<u32> {
+        let mut res = sequence(n, seed)
+            .into_iter()
+            .map(|x| (x % BASE) as u32)
+            .collect::<Vec<_>>();
+        if let Some(last) = res.last_mut() {
+            *last = (*last).max(1);
+        }
+        res
+    }
+
+    #[test]
+    fn test_large() {
+        for (n, m) in [(40, 40), (100, 37), (600, 700), (1000, 3)] {
+            let a = random(n, n as u64);
+            let b = random(m, m as u64 + 100);
+            let expected = mul_school(&a, &b);
+            assert_eq!(mul_karatsuba(&a, &b), expected);
+            if n.min(m) > 100 {
+                assert_eq!(mul_ntt(&a, &b), expected);
+            }
+
+            let a = BigUint { limbs: a };
+            let b = BigUint { limbs: b };
+            let c = &a * &b;
+            assert_eq!(&c / &b, a);
+            assert_eq!(&c % &b, BigUint::from(0u32));
+            let d = &c + &b - BigUint::from(1u32);
+            let (q, r) = d.div_rem(&a);
+            assert!(r < a);
+            assert_eq!(&q * &a + &r, d);
+            assert_eq!(d.to_string().parse::<BigUint>().unwrap(), d);
+        }
+        let two = BigUint::from(2u32);
+        assert_eq!(two.pow(127), BigUint::from(1u128 << 127));
+        assert_eq!(
+            BigUint::from(10u32).pow(30).div_rem_small(7),
+            ("142857142857142857142857142857".parse().unwrap(), 1)
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let mut g = DirectedGraph::new(3);
+        let huge = BigUint::from(10u32).pow(40);
+        g.add_weighted_edge(0, 1, &huge);
+        g.add_weighted_edge(1, 2, &huge);
+        g.add_weighted_edge(0, 2, &(&huge * &BigUint::from(3u32)));
+        let res = g.dijkstra(0);
+        assert_eq!(res.dist[2], Some(&huge + &huge));
+        assert_eq!(res.prv[2], Some(1));
+    }
+}
@@ -0,0 +1,30 @@
+/// least and greatest values of a totally ordered type.
+pub trait Bounded: Ord + Sized {
+    fn min_value() -> Self;
+    fn max_value() -> Self;
+}
+
+macro_rules! impl_bounded {
+    ($t:ty) => {
+        impl Bounded for $t {
+            fn min_value() -> Self {
+                <$t>::MIN
+            }
+            fn max_value() -> Self {
+                <$t>::MAX
+            }
+        }
+    };
+}
+
+impl_bounded!(usize);
+impl_bounded!(i8);
+impl_bounded!(i16);
+impl_bounded!(i32);
+impl_bounded!(i64);
+impl_bounded!(i128);
+impl_bounded!(u8);
+impl_bounded!(u16);
+impl_bounded!(u32);
+impl_bounded!(u64);
+impl_bounded!(u128);
@@ -0,0 +1,266 @@
+use modint::ModIntBase;
+
+fn from_usize<M: ModIntBase>(n: usize) -> M {
+    M::raw((n % M::modulus() as usize) as u32)
+}
+
+/// # Combinatorics
+/// factorials and inverse factorials up to n modulo a prime.
+/// ```
+/// use combinatorics::Combinatorics;
+/// use modint::ModInt998244353 as Mint;
+///
+/// let comb = Combinatorics::<Mint>::new(100);
+/// assert_eq!(comb.binom(5, 2), Mint::new(10));
+/// assert_eq!(comb.perm(5, 2), Mint::new(20));
+/// assert_eq!(comb.multinomial(&[1, 2, 3]), Mint::new(60));
+/// assert_eq!(comb.catalan(4), Mint::new(14));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Combinatorics<M: ModIntBase> {
+    fact: Vec<M>,
+    inv_fact: Vec<M>,
+}
+
+impl<M: ModIntBase> Combinatorics<M> {
+    /// `n` must be less than the modulus.
+    pub fn new(n: usize) -> Self {
+        assert!(n < M::modulus() as usize);
+        let mut fact = vec![M::one(); n + 1];
+        for i in 1..=n {
+            fact[i] = fact[i - 1] * from_usize(i);
+        }
+        let mut inv_fact = vec![M::one(); n + 1];
+        inv_fact[n] = fact[n].inv();
+        for i in (1..=n).rev() {
+            inv_fact[i - 1] = inv_fact[i] * from_usize(i);
+        }
+        Combinatorics { fact, inv_fact }
+    }
+
+    /// largest n the table covers.
+    pub fn max_n(&self) -> usize {
+        self.fact.len() - 1
+    }
+
+    pub fn fact(&self, n: usize) -> M {
+        self.fact[n]
+    }
+
+    pub fn inv_fact(&self, n: usize) -> M {
+        self.inv_fact[n]
+    }
+
+    /// 1 / n for 1 <= n.
+    pub fn inv(&self, n: usize) -> M {
+        assert!(n >= 1);
+        self.inv_fact[n] * self.fact[n - 1]
+    }
+
+    /// nCk. 0 if k > n.
+    pub fn binom(&self, n: usize, k: usize) -> M {
+        if k > n {
+            return M::zero();
+        }
+        self.fact[n] * self.inv_fact[k] * self.inv_fact[n - k]
+    }
+
+    /// nPk. 0 if k > n.
+    pub fn perm(&self, n: usize, k: usize) -> M {
+        if k > n {
+            return M::zero();
+        }
+        self.fact[n] * self.inv_fact[n - k]
+    }
+
+    /// nHk = (n+k-1)Ck, the number of multisets of size k from n kinds.
+    pub fn homo(&self, n: usize, k: usize) -> M {
+        if n == 0 {
+            return if k == 0 { M::one() } else { M::zero() };
+        }
+        self.binom(n + k - 1, k)
+    }
+
+    /// (k_1 + ... + k_m)! / (k_1! ... k_m!).
+    pub fn multinomial(&self, ks: &[usize]) -> M {
+        let n = ks.iter().sum::<usize>();
+        ks.iter()
+            .fold(self.fact[n], |acc, &k| acc * self.inv_fact[k])
+    }
+
+    /// n-th Catalan number (2n)! / (n! (n+1)!).
+    pub fn catalan(&self, n: usize) -> M {
+        self.fact[2 * n] * self.inv_fact[n] * self.inv_fact[n + 1]
+    }
+
+    /// Stirling number of the second kind S(n, k) in O(k log n).
+    pub fn stirling2(&self, n: usize, k: usize) -> M {
+        (0..=k).fold(M::zero(), |acc, i| {
+            let term = self.binom(k, i) * from_usize::<M>(k - i).pow(n as u64);
+            if i % 2 == 0 {
+                acc + term
+            } else {
+                acc - term
+            }
+        }) * self.inv_fact[k]
+    }
+
+    /// Bell number B(n) = sum_k S(n, k) in O(n log n).
+    pub fn bell(&self, n: usize) -> M {
+        // B(n) = sum_{j=0}^{n} j^n / j! * sum_{i=0}^{n-j} (-1)^i / i!
+        let mut alt = vec![M::zero(); n + 2];
+        for i in 0..=n {
+            let term = self.inv_fact[i];
+            alt[i + 1] = if i % 2 == 0 {
+                alt[i] + term
+            } else {
+                alt[i] - term
+            };
+        }
+        (0..=n).fold(M::zero(), |acc, j| {
+            acc + from_usize::<M>(j).pow(n as u64) * self.inv_fact[j] * alt[n - j + 1]
+        })
+    }
+
+    /// nCk modulo a small prime p by Lucas's theorem. the table must cover p - 1.
+    /// ```
+    /// use combinatorics::Combinatorics;
+    /// use modint::StaticModInt;
+    ///
+    /// let comb = Combinatorics::<StaticModInt<7>>::new(6);
+    /// assert_eq!(comb.lucas(1_000_000_000_000, 49), StaticModInt::new(0));
+    /// assert_eq!(comb.lucas(10, 3), StaticModInt::new(120));
+    /// ```
+    pub fn lucas(&self, n: u64, k: u64) -> M {
+        let p = M::modulus() as u64;
+        assert!(self.max_n() as u64 >= p - 1);
+        let (mut n, mut k) = (n, k);
+        let mut res = M::one();
+        while k > 0 {
+            res *= self.binom((n % p) as usize, (k % p) as usize);
+            n /= p;
+            k /= p;
+        }
+        res
+    }
+}
+
+/// unsigned Stirling numbers of the first kind \[n\]\[k\] for n, k <= `n` in O(n^2).
+pub fn stirling1_table<M: ModIntBase>(n: usize) -> Vec<Vec<M>> {
+    let mut res = vec![vec![M::zero(); n + 1]; n + 1];
+    res[0][0] = M::one();
+    for i in 1..=n {
+        for k in 1..=i {
+            res[i][k] = res[i - 1][k - 1] + from_usize::<M>(i - 1) * res[i - 1][k];
+        }
+    }
+    res
+}
+
+/// Stirling numbers of the second kind \[n\]\[k\] for n, k <= `n` in O(n^2).
+pub fn stirling2_table<M: ModIntBase>(n: usize) -> Vec<Vec<M>> {
+    let mut res = vec![vec![M::zero(); n + 1]; n + 1];
+    res[0][0] = M::one();
+    for i in 1..=n {
+        for k in 1..=i {
+            res[i][k] = res[i - 1][k - 1] + from_usize::<M>(k) * res[i - 1][k];
+        }
+    }
+    res
+}
+
+/// partition numbers p(0), ..., p(n) by the pentagonal number theorem in O(n sqrt(n)).
+/// ```
+/// use combinatorics::partition_table;
+/// use modint::ModInt998244353 as Mint;
+///
+/// assert_eq!(partition_table::<Mint>(7), [1, 1, 2, 3, 5, 7, 11, 15].map(Mint::new));
+/// ```
+pub fn partition_table<M: ModIntBase>(n: usize) -> Vec<M> {
+    let mut res = vec![M::zero(); n + 1];
+    res[0] = M::one();
+    for i in 1..=n {
+        let mut k = 1;
+        loop {
+            let g1 = k * (3 * k - 1) / 2;
+            if g1 > i {
+                break;
+            }
+            let g2 = k * (3 * k + 1) / 2;
+            let mut term = res[i - g1];
+            if g2 <= i {
+                term += res[i - g2];
+            }
+            if k % 2 == 1 {
+                res[i] += term;
+            } else {
+                res[i] -= term;
+            }
+            k += 1;
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use modint::{ModInt1000000007, ModInt998244353 as Mint, StaticModInt};
+
+    use crate::{partition_table, stirling1_table, stirling2_table, Combinatorics};
+
+    #[test]
+    fn test_binom() {
+        let comb = Combinatorics::<Mint>::new(200);
+        let mut pascal = vec![vec![Mint::new(0); 101]; 101];
+        for n in 0..=100 {
+            pascal[n][0] = Mint::new(1);
+            for k in 1..=n {
+                pascal[n][k] = pascal[n - 1][k - 1] + pascal[n - 1][k];
+            }
+            for (k, &x) in pascal[n].iter().enumerate() {
+                assert_eq!(comb.binom(n, k), x);
+            }
+        }
+        for n in 1..=200 {
+            assert_eq!(comb.inv(n) * Mint::new(n), Mint::new(1));
+        }
+        let comb = Combinatorics::<ModInt1000000007>::new(10);
+        assert_eq!(comb.fact(10), ModInt1000000007::new(3628800));
+        assert_eq!(comb.homo(3, 2), ModInt1000000007::new(6));
+    }
+
+    #[test]
+    fn test_stirling_bell() {
+        let n = 30;
+        let comb = Combinatorics::<Mint>::new(n);
+        let s1 = stirling1_table::<Mint>(n);
+        let s2 = stirling2_table::<Mint>(n);
+        for i in 0..=n {
+            for (k, &x) in s2[i].iter().enumerate() {
+                assert_eq!(comb.stirling2(i, k), x);
+            }
+            assert_eq!(s1[i].iter().copied().sum::<Mint>(), comb.fact(i));
+            assert_eq!(comb.bell(i), s2[i].iter().copied().sum::<Mint>());
+        }
+        assert_eq!(comb.bell(10), Mint::new(115975));
+        assert_eq!(s1[5][2], Mint::new(50));
+    }
+
+    #[test]
+    fn test_lucas_partition() {
+        type F = StaticModInt<13>;
+        let comb = Combinatorics::<F>::new(12);
+        let mut pascal = vec![vec![F::new(0); 301]; 301];
+        for n in 0..=300 {
+            pascal[n][0] = F::new(1);
+            for k in 1..=n {
+                pascal[n][k] = pascal[n - 1][k - 1] + pascal[n - 1][k];
+            }
+            for (k, &x) in pascal[n].iter().enumerate() {
+                assert_eq!(comb.lucas(n as u64, k as u64), x);
+            }
+        }
+        let p = partition_table::<Mint>(100);
+        assert_eq!(p[100], Mint::new(190569292));
+    }
+}
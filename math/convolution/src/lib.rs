@@ -0,0 +1,368 @@
+use modint::{ModIntBase, StaticModInt};
+
+/// smallest primitive root of prime `m`.
+fn primitive_root(m: u32) -> u32 {
+    if m == 2 {
+        return 1;
+    }
+    let mut factors = vec![];
+    let mut x = m - 1;
+    let mut p = 2;
+    while p * p <= x {
+        if x.is_multiple_of(p) {
+            factors.push(p);
+            while x.is_multiple_of(p) {
+                x /= p;
+            }
+        }
+        p += 1;
+    }
+    if x > 1 {
+        factors.push(x);
+    }
+    let pow = |a: u64, n: u32| -> u64 {
+        let mut res = 1u64;
+        let mut a = a;
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                res = res * a % m as u64;
+            }
+            a = a * a % m as u64;
+            n >>= 1;
+        }
+        res
+    };
+    (2..m)
+        .find(|&g| factors.iter().all(|&q| pow(g as u64, (m - 1) / q) != 1))
+        .unwrap()
+}
+
+/// roots\[k\] = primitive 2^(k+1)-th root of unity.
+fn roots<const M: u32>(inverse: bool) -> Vec<StaticModInt<M>> {
+    let rank = (M - 1).trailing_zeros();
+    let mut w = StaticModInt::<M>::new(primitive_root(M)).pow(((M - 1) >> rank) as u64);
+    if inverse {
+        w = w.inv();
+    }
+    let mut res = vec![w; rank as usize];
+    for k in (0..(rank as usize - 1)).rev() {
+        res[k] = res[k + 1] * res[k + 1];
+    }
+    res
+}
+
+fn bit_reverse<T>(a: &mut [T]) {
+    let n = a.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            a.swap(i, j);
+        }
+    }
+}
+
+fn butterfly<const M: u32>(a: &mut [StaticModInt<M>], inverse: bool) {
+    let n = a.len();
+    assert!(n.is_power_of_two());
+    assert!(n.trailing_zeros() <= (M - 1).trailing_zeros());
+    let roots = roots::<M>(inverse);
+    bit_reverse(a);
+    let mut len = 1;
+    let mut k = 0;
+    while len < n {
+        let w = roots[k];
+        for start in (0..n).step_by(2 * len) {
+            let mut z = StaticModInt::<M>::new(1);
+            for i in start..(start + len) {
+                let x = a[i];
+                let y = a[i + len] * z;
+                a[i] = x + y;
+                a[i + len] = x - y;
+                z *= w;
+            }
+        }
+        len <<= 1;
+        k += 1;
+    }
+}
+
+/// number-theoretic transform. the length of `a` must be a power of two dividing `M - 1`.
+pub fn ntt<const M: u32>(a: &mut [StaticModInt<M>]) {
+    butterfly(a, false);
+}
+
+/// inverse of `ntt`, including the division by the length.
+pub fn intt<const M: u32>(a: &mut [StaticModInt<M>]) {
+    butterfly(a, true);
+    let inv = StaticModInt::<M>::new(a.len()).inv();
+    for x in a.iter_mut() {
+        *x *= inv;
+    }
+}
+
+fn convolution_naive<T: ModIntBase>(a: &[T], b: &[T]) -> Vec<T> {
+    let mut res = vec![T::zero(); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            res[i + j] += x * y;
+        }
+    }
+    res
+}
+
+/// c\[k\] = sum_{i + j = k} a\[i\] b\[j\] modulo an NTT-friendly prime `M` (e.g. 998244353).
+/// ```
+/// use convolution::convolution;
+/// use modint::ModInt998244353 as Mint;
+///
+/// let a = [1, 2, 3].map(Mint::new);
+/// let b = [4, 5].map(Mint::new);
+/// assert_eq!(convolution(&a, &b), [4, 13, 22, 15].map(Mint::new));
+/// ```
+pub fn convolution<const M: u32>(
+    a: &[StaticModInt<M>],
+    b: &[StaticModInt<M>],
+) -> Vec<StaticModInt<M>> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    if a.len().min(b.len()) <= 60 {
+        return convolution_naive(a, b);
+    }
+    let len = a.len() + b.len() - 1;
+    let n = len.next_power_of_two();
+    let mut fa = a.to_vec();
+    fa.resize(n, StaticModInt::new(0));
+    let mut fb = b.to_vec();
+    fb.resize(n, StaticModInt::new(0));
+    ntt(&mut fa);
+    ntt(&mut fb);
+    for (x, y) in fa.iter_mut().zip(&fb) {
+        *x *= *y;
+    }
+    intt(&mut fa);
+    fa.truncate(len);
+    fa
+}
+
+const M1: u32 = 754974721;
+const M2: u32 = 167772161;
+const M3: u32 = 469762049;
+
+/// convolution of `a` and `b` taken modulo `M`.
+fn residues<const M: u32, T>(a: &[T], b: &[T]) -> Vec<u64>
+where
+    T: Copy,
+    StaticModInt<M>: From<T>,
+{
+    let a = a
+        .iter()
+        .map(|&x| StaticModInt::<M>::new(x))
+        .collect::<Vec<_>>();
+    let b = b
+        .iter()
+        .map(|&x| StaticModInt::<M>::new(x))
+        .collect::<Vec<_>>();
+    convolution(&a, &b)
+        .into_iter()
+        .map(|x| x.val() as u64)
+        .collect()
+}
+
+/// recover the values modulo `M1 M2 M3` (~2^85.6) from the residues by Garner's algorithm.
+fn crt3(c1: Vec<u64>, c2: Vec<u64>, c3: Vec<u64>) -> Vec<u128> {
+    let (m1, m2, m3) = (M1 as u64, M2 as u64, M3 as u64);
+    let m1_inv_m2 = StaticModInt::<M2>::new(M1).inv().val() as u64;
+    let m12_inv_m3 = StaticModInt::<M3>::new(m1 * m2).inv().val() as u64;
+    c1.into_iter()
+        .zip(c2)
+        .zip(c3)
+        .map(|((r1, r2), r3)| {
+            let t1 = (r2 + m2 - r1 % m2) % m2 * m1_inv_m2 % m2;
+            let x = r1 + m1 * t1;
+            let t2 = (r3 + m3 - x % m3) % m3 * m12_inv_m3 % m3;
+            x as u128 + (m1 as u128 * m2 as u128) * t2 as u128
+        })
+        .collect()
+}
+
+/// convolution modulo an arbitrary modulus.
+/// ```
+/// use convolution::convolution_arbitrary;
+/// use modint::ModInt1000000007 as Mint;
+///
+/// let a = [1_000_000_000, 2].map(Mint::new);
+/// let b = [1_000_000_000, 3].map(Mint::new);
+/// assert_eq!(convolution_arbitrary(&a, &b), [49, 999999972, 6].map(Mint::new));
+/// ```
+pub fn convolution_arbitrary<T: ModIntBase>(a: &[T], b: &[T]) -> Vec<T> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    if a.len().min(b.len()) <= 60 {
+        return convolution_naive(a, b);
+    }
+    let a = a.iter().map(|x| x.val()).collect::<Vec<_>>();
+    let b = b.iter().map(|x| x.val()).collect::<Vec<_>>();
+    let m = T::modulus() as u128;
+    crt3(
+        residues::<M1, _>(&a, &b),
+        residues::<M2, _>(&a, &b),
+        residues::<M3, _>(&a, &b),
+    )
+    .into_iter()
+    .map(|x| T::raw((x % m) as u32))
+    .collect()
+}
+
+/// exact convolution. every coefficient of the result must be less than `M1 M2 M3` (~2^85.6).
+pub fn convolution_u64(a: &[u64], b: &[u64]) -> Vec<u128> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    crt3(
+        residues::<M1, _>(a, b),
+        residues::<M2, _>(a, b),
+        residues::<M3, _>(a, b),
+    )
+}
+
+/// exact convolution. every coefficient of the result must be at most `M1 M2 M3 / 2` (~2^84.6)
+/// in absolute value.
+/// ```
+/// use convolution::convolution_i64;
+///
+/// assert_eq!(convolution_i64(&[1, -2], &[-3, 4]), vec![-3, 10, -8]);
+/// ```
+pub fn convolution_i64(a: &[i64], b: &[i64]) -> Vec<i128> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let m123 = M1 as i128 * M2 as i128 * M3 as i128;
+    crt3(
+        residues::<M1, _>(a, b),
+        residues::<M2, _>(a, b),
+        residues::<M3, _>(a, b),
+    )
+    .into_iter()
+    .map(|x| {
+        let x = x as i128;
+        if 2 * x >= m123 {
+            x - m123
+        } else {
+            x
+        }
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use modint::{ModInt1000000007, ModInt998244353, ModIntBase};
+
+    use crate::{convolution, convolution_arbitrary, convolution_i64, convolution_u64, M1, M2, M3};
+
+    fn naive<T: ModIntBase>(a: &[T], b: &[T]) -> Vec<T> {
+        let mut res = vec![T::zero(); a.len() + b.len() - 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                res[i + j] += a[i] * b[j];
+            }
+        }
+        res
+    }
+
+    /// deterministic pseudo random sequence.
+    fn sequence(n: usize, seed: u64) -> Vec<u64> {
+        let mut x = seed;
+        (0..n)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                x
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_convolution() {
+        for (n, m) in [(1, 1), (3, 100), (100, 100), (257, 300)] {
+            let a = sequence(n, 1)
+                .into_iter()
+                .map(ModInt998244353::new)
+                .collect::<Vec<_>>();
+            let b = sequence(m, 2)
+                .into_iter()
+                .map(ModInt998244353::new)
+                .collect::<Vec<_>>();
+            assert_eq!(convolution(&a, &b), naive(&a, &b));
+            let a = sequence(n, 3)
+                .into_iter()
+                .map(ModInt1000000007::new)
+                .collect::<Vec<_>>();
+            let b = sequence(m, 4)
+                .into_iter()
+                .map(ModInt1000000007::new)
+                .collect::<Vec<_>>();
+            assert_eq!(convolution_arbitrary(&a, &b), naive(&a, &b));
+        }
+        assert!(convolution::<998244353>(&[], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_exact() {
+        let a = sequence(200, 5)
+            .into_iter()
+            .map(|x| x >> 24)
+            .collect::<Vec<_>>();
+        let b = sequence(150, 6)
+            .into_iter()
+            .map(|x| x >> 24)
+            .collect::<Vec<_>>();
+        let mut expected = vec![0u128; a.len() + b.len() - 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                expected[i + j] += a[i] as u128 * b[j] as u128;
+            }
+        }
+        assert_eq!(convolution_u64(&a, &b), expected);
+
+        let a = a.iter().map(|&x| x as i64 - (1 << 39)).collect::<Vec<_>>();
+        let b = b.iter().map(|&x| (1 << 39) - x as i64).collect::<Vec<_>>();
+        let mut expected = vec![0i128; a.len() + b.len() - 1];
+        for i in 0..a.len() {
+            for j in 0..b.len() {
+                expected[i + j] += a[i] as i128 * b[j] as i128;
+            }
+        }
+        assert_eq!(convolution_i64(&a, &b), expected);
+    }
+
+    #[test]
+    fn test_exact_boundary() {
+        // the coefficient at index 1 is hi 2^32 + lo.
+        let m123 = M1 as u128 * M2 as u128 * M3 as u128;
+        let split = |x: u128| ((x >> 32) as u64, (x & 0xffff_ffff) as u64);
+        let (hi, lo) = split(m123 - 1);
+        assert_eq!(
+            convolution_u64(&[hi, lo], &[1, 1 << 32]),
+            [hi as u128, m123 - 1, (lo as u128) << 32]
+        );
+        let (hi, lo) = split(m123);
+        assert_eq!(convolution_u64(&[hi, lo], &[1, 1 << 32])[1], 0);
+
+        let (hi, lo) = split(m123 / 2);
+        let (hi, lo) = (hi as i64, lo as i64);
+        let max = (m123 / 2) as i128;
+        assert_eq!(convolution_i64(&[hi, lo], &[1, 1 << 32])[1], max);
+        assert_eq!(convolution_i64(&[hi, lo], &[-1, -1 << 32])[1], -max);
+    }
+}
@@ -0,0 +1,324 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Mul};
+
+use magma::Magma;
+use monoid::Monoid;
+use one::One;
+use zero::Zero;
+
+/// sum_{i=0}^{n-1} floor((a i + b) / m), i.e. the number of lattice points (i, j)
+/// with 0 <= i < n and 0 < j <= (a i + b) / m when a, b >= 0.
+/// `a` and `b` may be negative.
+/// ```
+/// use floor_sum::floor_sum;
+///
+/// assert_eq!(floor_sum(4, 10, 6, 3), 0 + 0 + 1 + 2);
+/// assert_eq!(floor_sum(3, 2, -1, 0), 0 - 1 - 1);
+/// ```
+pub fn floor_sum(n: i64, m: i64, a: i64, b: i64) -> i64 {
+    assert!(n >= 0 && m >= 1);
+    let (n, m, mut a, mut b) = (n as i128, m as i128, a as i128, b as i128);
+    let mut ans = 0;
+    if a < 0 || a >= m {
+        let a2 = a.rem_euclid(m);
+        ans += n * (n - 1) / 2 * ((a - a2) / m);
+        a = a2;
+    }
+    if b < 0 || b >= m {
+        let b2 = b.rem_euclid(m);
+        ans += n * ((b - b2) / m);
+        b = b2;
+    }
+    (ans + floor_sum_unsigned(n, m, a, b)) as i64
+}
+
+fn floor_sum_unsigned(n: i128, m: i128, a: i128, b: i128) -> i128 {
+    let (mut n, mut m, mut a, mut b) = (n, m, a, b);
+    let mut ans = 0;
+    loop {
+        if a >= m {
+            ans += n * (n - 1) / 2 * (a / m);
+            a %= m;
+        }
+        if b >= m {
+            ans += n * (b / m);
+            b %= m;
+        }
+        let y_max = a * n + b;
+        if y_max < m {
+            break;
+        }
+        n = y_max / m;
+        b = y_max % m;
+        std::mem::swap(&mut m, &mut a);
+    }
+    ans
+}
+
+fn pow<M: Monoid>(x: M::S, n: u64) -> M::S
+where
+    M::S: Clone,
+{
+    let mut res = M::identity();
+    let mut x = x;
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            res = M::binary_operation(res, x.clone());
+        }
+        n >>= 1;
+        if n > 0 {
+            x = M::binary_operation(x.clone(), x);
+        }
+    }
+    res
+}
+
+/// same as `universal_euclid` with 0 <= b < c.
+fn euclid<M: Monoid>(n: u64, a: u64, b: u64, c: u64, u: M::S, r: M::S) -> M::S
+where
+    M::S: Clone,
+{
+    if n == 0 {
+        return M::identity();
+    }
+    if a >= c {
+        let r = M::binary_operation(pow::<M>(u.clone(), a / c), r);
+        return euclid::<M>(n, a % c, b, c, u, r);
+    }
+    let m = ((a as u128 * n as u128 + b as u128) / c as u128) as u64;
+    if m == 0 {
+        return pow::<M>(r, n);
+    }
+    let rest = n - ((c as u128 * m as u128 - b as u128 - 1) / a as u128) as u64;
+    let head = M::binary_operation(pow::<M>(r.clone(), (c - b - 1) / a), u.clone());
+    let mid = euclid::<M>(m - 1, c, (c - b - 1) % a, a, r.clone(), u);
+    M::binary_operation(M::binary_operation(head, mid), pow::<M>(r, rest))
+}
+
+/// # Universal Euclidean Algorithm
+/// walk along the line y = (a x + b) / c for x = 1, ..., n and multiply `u` each time
+/// y passes an integer (including floor(b / c) times at the start) and `r` at each x.
+/// that is, the product of u^{floor(b/c)} and u^{f(x) - f(x-1)} r for x = 1, ..., n
+/// with f(x) = floor((a x + b) / c), in O(log(max(a, c)) log n) operations.
+/// ```
+/// use floor_sum::universal_euclid;
+/// use magma::Magma;
+/// use monoid::Monoid;
+///
+/// struct Concat;
+/// impl Magma for Concat {
+///     type S = String;
+///     fn binary_operation(lhs: String, rhs: String) -> String {
+///         lhs + &rhs
+///     }
+/// }
+/// impl Monoid for Concat {
+///     fn identity() -> String {
+///         String::new()
+///     }
+/// }
+///
+/// let s = universal_euclid::<Concat>(4, 3, 1, 2, "U".to_string(), "R".to_string());
+/// assert_eq!(s, "UURURUURUR");
+/// ```
+pub fn universal_euclid<M: Monoid>(n: u64, a: u64, b: u64, c: u64, u: M::S, r: M::S) -> M::S
+where
+    M::S: Clone,
+{
+    assert!(c >= 1);
+    M::binary_operation(
+        pow::<M>(u.clone(), b / c),
+        euclid::<M>(n, a, b % c, c, u, r),
+    )
+}
+
+/// segment of the walk in `universal_euclid`: `x` steps of r, `y` steps of u,
+/// p\[k\] = sum of x^k and s\[k\] = sum of x^k y over the r steps (x, y counted from the start).
+#[derive(Clone, Copy)]
+struct FloorSumNode<T, const K: usize> {
+    x: T,
+    y: T,
+    p: [T; K],
+    s: [T; K],
+}
+
+struct FloorSumMonoid<T, const K: usize>(PhantomData<T>);
+
+impl<T, const K: usize> Magma for FloorSumMonoid<T, K>
+where
+    T: Copy + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    type S = FloorSumNode<T, K>;
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        let mut binom = [[T::zero(); K]; K];
+        let mut x_pow = [T::one(); K];
+        for k in 0..K {
+            binom[k][0] = T::one();
+            for j in 1..=k {
+                binom[k][j] = binom[k - 1][j - 1] + binom[k - 1][j];
+            }
+            if k > 0 {
+                x_pow[k] = x_pow[k - 1] * lhs.x;
+            }
+        }
+        let mut p = lhs.p;
+        let mut s = lhs.s;
+        for k in 0..K {
+            for j in 0..=k {
+                let c = binom[k][j] * x_pow[k - j];
+                p[k] = p[k] + c * rhs.p[j];
+                s[k] = s[k] + c * (rhs.s[j] + lhs.y * rhs.p[j]);
+            }
+        }
+        FloorSumNode {
+            x: lhs.x + rhs.x,
+            y: lhs.y + rhs.y,
+            p,
+            s,
+        }
+    }
+}
+
+impl<T, const K: usize> Monoid for FloorSumMonoid<T, K>
+where
+    T: Copy + Zero + One + Add<Output = T> + Mul<Output = T>,
+{
+    fn identity() -> Self::S {
+        FloorSumNode {
+            x: T::zero(),
+            y: T::zero(),
+            p: [T::zero(); K],
+            s: [T::zero(); K],
+        }
+    }
+}
+
+/// \[sum_{i=0}^{n-1} i^k floor((a i + b) / m) for k in 0..K\], in O(K^2 log) operations on `T`.
+/// ```
+/// use floor_sum::floor_sum_powers;
+///
+/// // floor((2 i + 1) / 3) = 0, 1, 1, 2, 3 for i = 0, ..., 4
+/// assert_eq!(floor_sum_powers::<i128, 3>(5, 3, 2, 1), [7, 21, 71]);
+/// ```
+pub fn floor_sum_powers<T, const K: usize>(n: u64, m: u64, a: u64, b: u64) -> [T; K]
+where
+    T: Copy + Zero + One + Add<Output = T> + Mul<Output = T> + From<u64>,
+{
+    let mut res = [T::zero(); K];
+    if n == 0 || K == 0 {
+        return res;
+    }
+    let u = FloorSumNode {
+        x: T::zero(),
+        y: T::one(),
+        p: [T::zero(); K],
+        s: [T::zero(); K],
+    };
+    let r = FloorSumNode {
+        x: T::one(),
+        y: T::zero(),
+        p: [T::one(); K],
+        s: [T::zero(); K],
+    };
+    let node = universal_euclid::<FloorSumMonoid<T, K>>(n - 1, a, b, m, u, r);
+    res.copy_from_slice(&node.s);
+    // the term for i = 0 is only counted in k = 0.
+    res[0] = res[0] + T::from(b / m);
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use magma::Magma;
+    use modint::ModInt998244353 as Mint;
+    use monoid::Monoid;
+
+    use crate::{floor_sum, floor_sum_powers, universal_euclid};
+
+    #[test]
+    fn test_floor_sum() {
+        for n in 0..20i64 {
+            for m in 1..20 {
+                for a in -20..20 {
+                    for b in -20..20 {
+                        let naive = (0..n).map(|i| (a * i + b).div_euclid(m)).sum::<i64>();
+                        assert_eq!(floor_sum(n, m, a, b), naive);
+                    }
+                }
+            }
+        }
+        let n = 1_000_000;
+        assert_eq!(
+            floor_sum(n, 1_000_000_000, 999_999_999, 999_999_999),
+            (0..n)
+                .map(|i| (999_999_999 * i + 999_999_999) / 1_000_000_000)
+                .sum::<i64>()
+        );
+    }
+
+    struct Concat;
+    impl Magma for Concat {
+        type S = String;
+        fn binary_operation(lhs: String, rhs: String) -> String {
+            lhs + &rhs
+        }
+    }
+    impl Monoid for Concat {
+        fn identity() -> String {
+            String::new()
+        }
+    }
+
+    #[test]
+    fn test_universal_euclid() {
+        for n in 0..15 {
+            for a in 0..15 {
+                for b in 0..15 {
+                    for c in 1..15 {
+                        let f = |x: u64| (a * x + b) / c;
+                        let mut naive = "U".repeat(f(0) as usize);
+                        for x in 1..=n {
+                            naive += &"U".repeat((f(x) - f(x - 1)) as usize);
+                            naive += "R";
+                        }
+                        let res = universal_euclid::<Concat>(
+                            n,
+                            a,
+                            b,
+                            c,
+                            "U".to_string(),
+                            "R".to_string(),
+                        );
+                        assert_eq!(res, naive);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_floor_sum_powers() {
+        for n in 0..12u64 {
+            for m in 1..12 {
+                for a in 0..12 {
+                    for b in 0..12 {
+                        let mut naive = [0; 4];
+                        for i in 0..n {
+                            for (k, v) in naive.iter_mut().enumerate() {
+                                *v += i.pow(k as u32) as i128 * ((a * i + b) / m) as i128;
+                            }
+                        }
+                        assert_eq!(floor_sum_powers::<i128, 4>(n, m, a, b), naive);
+                    }
+                }
+            }
+        }
+        let (n, m, a, b) = (1_000_000_000u64, 998_244_353, 123_456_789, 987_654_321);
+        let res = floor_sum_powers::<Mint, 3>(n, m, a, b);
+        assert_eq!(
+            res[0],
+            Mint::new(floor_sum(n as i64, m as i64, a as i64, b as i64))
+        );
+    }
+}
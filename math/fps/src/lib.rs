@@ -0,0 +1,420 @@
+use std::ops::{Add, Deref, DerefMut, Mul, Neg, Sub};
+
+use convolution::convolution;
+use modint::StaticModInt;
+use number_theory::sqrt_mod;
+
+/// # Formal Power Series
+/// coefficients modulo an NTT-friendly prime `M`, in increasing order of degree.
+/// methods taking `n` return the result modulo x^n.
+/// ```
+/// use fps::FormalPowerSeries;
+/// use modint::ModInt998244353 as Mint;
+///
+/// // 1 / (1 - x - x^2)
+/// let f = FormalPowerSeries::new([1, -1, -1].map(Mint::new).to_vec());
+/// assert_eq!(f.inv(6).to_vec(), [1, 1, 2, 3, 5, 8].map(Mint::new));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct FormalPowerSeries<const M: u32> {
+    coef: Vec<StaticModInt<M>>,
+}
+
+pub type Fps998244353 = FormalPowerSeries<998244353>;
+
+impl<const M: u32> Deref for FormalPowerSeries<M> {
+    type Target = Vec<StaticModInt<M>>;
+    fn deref(&self) -> &Self::Target {
+        &self.coef
+    }
+}
+
+impl<const M: u32> DerefMut for FormalPowerSeries<M> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.coef
+    }
+}
+
+impl<const M: u32> From<Vec<StaticModInt<M>>> for FormalPowerSeries<M> {
+    fn from(coef: Vec<StaticModInt<M>>) -> Self {
+        FormalPowerSeries { coef }
+    }
+}
+
+impl<const M: u32> FormalPowerSeries<M> {
+    pub fn new(coef: Vec<StaticModInt<M>>) -> Self {
+        FormalPowerSeries { coef }
+    }
+
+    fn zero() -> StaticModInt<M> {
+        StaticModInt::new(0)
+    }
+
+    fn one() -> StaticModInt<M> {
+        StaticModInt::new(1)
+    }
+
+    /// the first `n` coefficients, padded with zeros.
+    pub fn prefix(&self, n: usize) -> Self {
+        let mut coef = self.coef[..n.min(self.len())].to_vec();
+        coef.resize(n, Self::zero());
+        Self::new(coef)
+    }
+
+    /// remove trailing zeros.
+    pub fn shrink(&mut self) {
+        while self.coef.last().is_some_and(|x| *x == Self::zero()) {
+            self.coef.pop();
+        }
+    }
+
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &x)| x * StaticModInt::new(i))
+                .collect(),
+        )
+    }
+
+    pub fn integral(&self) -> Self {
+        let n = self.len();
+        let mut inv = vec![Self::one(); n + 1];
+        for i in 2..=n {
+            inv[i] = -inv[M as usize % i] * StaticModInt::new(M as usize / i);
+        }
+        let mut coef = Vec::with_capacity(n + 1);
+        coef.push(Self::zero());
+        coef.extend(self.iter().zip(&inv[1..]).map(|(&x, &y)| x * y));
+        Self::new(coef)
+    }
+
+    /// 1 / f. the constant term must not be zero.
+    pub fn inv(&self, n: usize) -> Self {
+        assert!(!self.is_empty() && self[0] != Self::zero());
+        let mut g = Self::new(vec![self[0].inv()]);
+        let mut m = 1;
+        while m < n {
+            m *= 2;
+            let h = (self.prefix(m) * g.clone()).prefix(m);
+            let h = (h * g.clone()).prefix(m);
+            g = (g.clone() + g - h).prefix(m);
+        }
+        g.prefix(n)
+    }
+
+    /// log f. the constant term must be one.
+    pub fn log(&self, n: usize) -> Self {
+        assert!(!self.is_empty() && self[0] == Self::one());
+        if n == 0 {
+            return Self::default();
+        }
+        (self.derivative() * self.inv(n)).prefix(n - 1).integral()
+    }
+
+    /// exp f. the constant term must be zero.
+    pub fn exp(&self, n: usize) -> Self {
+        assert!(self.is_empty() || self[0] == Self::zero());
+        let mut g = Self::new(vec![Self::one()]);
+        let mut m = 1;
+        while m < n {
+            m *= 2;
+            let mut h = self.prefix(m) - g.log(m);
+            h[0] += Self::one();
+            g = (g * h).prefix(m);
+        }
+        g.prefix(n)
+    }
+
+    /// f^k.
+    pub fn pow(&self, k: u64, n: usize) -> Self {
+        if k == 0 {
+            return Self::new(vec![Self::one()]).prefix(n);
+        }
+        let i = match self.iter().position(|x| *x != Self::zero()) {
+            Some(i) => i,
+            None => return Self::default().prefix(n),
+        };
+        if i > 0 && k >= n.div_ceil(i) as u64 {
+            return Self::default().prefix(n);
+        }
+        let shift = i * k as usize;
+        let c = self[i];
+        let c_inv = c.inv();
+        let g = Self::new(self[i..].iter().map(|&x| x * c_inv).collect());
+        let g = g.log(n - shift) * StaticModInt::new(k);
+        let g = g.exp(n - shift) * c.pow(k);
+        let mut coef = vec![Self::zero(); shift];
+        coef.extend(g.iter());
+        Self::new(coef)
+    }
+
+    /// some g with g^2 = f, if exists.
+    pub fn sqrt(&self, n: usize) -> Option<Self> {
+        let i = match self.iter().position(|x| *x != Self::zero()) {
+            Some(i) => i,
+            None => return Some(Self::default().prefix(n)),
+        };
+        if i % 2 == 1 {
+            return None;
+        }
+        if i / 2 >= n {
+            return Some(Self::default().prefix(n));
+        }
+        let f = Self::new(self[i..].to_vec());
+        let m0 = n - i / 2;
+        let g0 = sqrt_mod(f[0].val() as u64, M as u64)?;
+        let mut g = Self::new(vec![StaticModInt::new(g0)]);
+        let inv2 = StaticModInt::<M>::new(2).inv();
+        let mut m = 1;
+        while m < m0 {
+            m *= 2;
+            g = ((f.prefix(m) * g.inv(m)).prefix(m) + g) * inv2;
+        }
+        let mut coef = vec![Self::zero(); i / 2];
+        coef.extend(g.prefix(m0).iter());
+        Some(Self::new(coef))
+    }
+
+    /// (quotient, remainder) as polynomials.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let mut f = self.clone();
+        f.shrink();
+        let mut g = rhs.clone();
+        g.shrink();
+        assert!(!g.is_empty());
+        if f.len() < g.len() {
+            return (Self::default(), f);
+        }
+        let k = f.len() - g.len() + 1;
+        let rev_f = Self::new(f.iter().rev().take(k).copied().collect());
+        let rev_g = Self::new(g.iter().rev().copied().collect());
+        let mut q = (rev_f * rev_g.inv(k)).prefix(k);
+        q.reverse();
+        let mut r = (f - g.clone() * q.clone()).prefix(g.len() - 1);
+        r.shrink();
+        (q, r)
+    }
+
+    /// evaluate as a polynomial.
+    pub fn eval(&self, x: StaticModInt<M>) -> StaticModInt<M> {
+        self.iter().rev().fold(Self::zero(), |acc, &c| acc * x + c)
+    }
+
+    /// evaluate as a polynomial at each of `xs`.
+    pub fn multipoint_eval(&self, xs: &[StaticModInt<M>]) -> Vec<StaticModInt<M>> {
+        if xs.is_empty() {
+            return vec![];
+        }
+        let tree = SubproductTree::new(xs);
+        let mut rem = vec![Self::default(); 2 * tree.size];
+        rem[1] = self.div_rem(&tree.node[1]).1;
+        for k in 2..(tree.size + xs.len()) {
+            rem[k] = rem[k / 2].div_rem(&tree.node[k]).1;
+        }
+        (0..xs.len())
+            .map(|i| rem[tree.size + i].first().copied().unwrap_or(Self::zero()))
+            .collect()
+    }
+
+    /// the polynomial of degree less than n passing through (xs\[i\], ys\[i\]).
+    /// `xs` must be distinct.
+    pub fn interpolate(xs: &[StaticModInt<M>], ys: &[StaticModInt<M>]) -> Self {
+        assert_eq!(xs.len(), ys.len());
+        if xs.is_empty() {
+            return Self::default();
+        }
+        let tree = SubproductTree::new(xs);
+        let w = tree.node[1].derivative().multipoint_eval(xs);
+        let mut f = vec![Self::default(); 2 * tree.size];
+        for i in 0..xs.len() {
+            f[tree.size + i] = Self::new(vec![ys[i] / w[i]]);
+        }
+        for k in (1..tree.size).rev() {
+            f[k] = f[2 * k].clone() * tree.node[2 * k + 1].clone()
+                + f[2 * k + 1].clone() * tree.node[2 * k].clone();
+        }
+        let mut res = f.swap_remove(1);
+        res.shrink();
+        res
+    }
+}
+
+/// node\[k\] = product of (x - xs\[i\]) over the leaves below k.
+struct SubproductTree<const M: u32> {
+    size: usize,
+    node: Vec<FormalPowerSeries<M>>,
+}
+
+impl<const M: u32> SubproductTree<M> {
+    fn new(xs: &[StaticModInt<M>]) -> Self {
+        let size = xs.len().next_power_of_two();
+        let one = FormalPowerSeries::new(vec![StaticModInt::new(1)]);
+        let mut node = vec![one; 2 * size];
+        for (i, &x) in xs.iter().enumerate() {
+            node[size + i] = FormalPowerSeries::new(vec![-x, StaticModInt::new(1)]);
+        }
+        for k in (1..size).rev() {
+            node[k] = node[2 * k].clone() * node[2 * k + 1].clone();
+        }
+        SubproductTree { size, node }
+    }
+}
+
+impl<const M: u32> Add for FormalPowerSeries<M> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (mut long, short) = if self.len() >= rhs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        for (x, y) in long.iter_mut().zip(short.iter()) {
+            *x += *y;
+        }
+        long
+    }
+}
+
+impl<const M: u32> Neg for FormalPowerSeries<M> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self::new(self.iter().map(|&x| -x).collect())
+    }
+}
+
+impl<const M: u32> Sub for FormalPowerSeries<M> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + (-rhs)
+    }
+}
+
+impl<const M: u32> Mul for FormalPowerSeries<M> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(convolution(&self, &rhs))
+    }
+}
+
+impl<const M: u32> Mul<StaticModInt<M>> for FormalPowerSeries<M> {
+    type Output = Self;
+    fn mul(self, rhs: StaticModInt<M>) -> Self {
+        Self::new(self.iter().map(|&x| x * rhs).collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use modint::ModInt998244353 as Mint;
+
+    use crate::Fps998244353 as Fps;
+
+    fn sequence(n: usize, seed: u64) -> Vec<Mint> {
+        let mut x = seed;
+        (0..n)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                Mint::new(x)
+            })
+            .collect()
+    }
+
+    fn naive_mul(a: &[Mint], b: &[Mint], n: usize) -> Vec<Mint> {
+        let mut res = vec![Mint::new(0); n];
+        for i in 0..a.len().min(n) {
+            for j in 0..b.len().min(n - i) {
+                res[i + j] += a[i] * b[j];
+            }
+        }
+        res
+    }
+
+    fn naive_inv(f: &[Mint], n: usize) -> Vec<Mint> {
+        let c = f[0].inv();
+        let mut g = vec![Mint::new(0); n];
+        for k in 0..n {
+            let mut s = if k == 0 { Mint::new(1) } else { Mint::new(0) };
+            for i in 1..=k.min(f.len() - 1) {
+                s -= f[i] * g[k - i];
+            }
+            g[k] = s * c;
+        }
+        g
+    }
+
+    /// g' = f' g, i.e. k g_k = sum i f_i g_{k-i}.
+    fn naive_exp(f: &[Mint], n: usize) -> Vec<Mint> {
+        let mut g = vec![Mint::new(0); n];
+        g[0] = Mint::new(1);
+        for k in 1..n {
+            let mut s = Mint::new(0);
+            for i in 1..=k.min(f.len() - 1) {
+                s += Mint::new(i) * f[i] * g[k - i];
+            }
+            g[k] = s / Mint::new(k);
+        }
+        g
+    }
+
+    #[test]
+    fn test_inv_log_exp() {
+        for n in [1, 2, 5, 64, 150] {
+            let mut f = sequence(n, 7);
+            f[0] = Mint::new(1);
+            let fps = Fps::new(f.clone());
+            assert_eq!(fps.inv(n).to_vec(), naive_inv(&f, n));
+            let log = fps.log(n);
+            assert_eq!(log.exp(n).to_vec(), f);
+            let mut g = sequence(n, 11);
+            g[0] = Mint::new(0);
+            assert_eq!(Fps::new(g.clone()).exp(n).to_vec(), naive_exp(&g, n));
+        }
+    }
+
+    #[test]
+    fn test_pow_sqrt() {
+        let n = 100;
+        let mut f = sequence(30, 13);
+        f[0] = Mint::new(0);
+        f[1] = Mint::new(0);
+        let fps = Fps::new(f.clone());
+        let mut expected = vec![Mint::new(0); n];
+        expected[0] = Mint::new(1);
+        for k in 0..=5 {
+            assert_eq!(fps.pow(k, n).to_vec(), expected);
+            expected = naive_mul(&expected, &f, n);
+        }
+        assert_eq!(fps.pow(60, n).to_vec(), vec![Mint::new(0); n]);
+
+        let sq = naive_mul(&f, &f, n);
+        let g = Fps::new(sq.clone()).sqrt(n).unwrap();
+        assert_eq!(naive_mul(&g, &g, n), sq);
+        f[2] = Mint::new(0);
+        assert!(Fps::new(f).sqrt(n).is_none());
+    }
+
+    #[test]
+    fn test_polynomial() {
+        let f = Fps::new(sequence(50, 17));
+        let g = Fps::new(sequence(20, 19));
+        let (q, r) = f.div_rem(&g);
+        assert_eq!(q.len(), 31);
+        assert!(r.len() < 20);
+        let mut back = naive_mul(&q, &g, 50);
+        for (x, y) in back.iter_mut().zip(r.iter()) {
+            *x += *y;
+        }
+        assert_eq!(back, f.to_vec());
+
+        let xs = sequence(37, 23);
+        let ys = f.multipoint_eval(&xs);
+        assert_eq!(ys, xs.iter().map(|&x| f.eval(x)).collect::<Vec<_>>());
+        let h = Fps::interpolate(&xs, &ys);
+        assert!(h.len() <= xs.len());
+        assert_eq!(h.multipoint_eval(&xs), ys);
+    }
+}
@@ -1,5 +1,9 @@
-use magma::AddMagma;
+use std::ops::{Add, Mul, Neg};
+
+use magma::{AddMagma, Affine, MulMagma, Pair, Rev, WrappingAddMagma, XorMagma};
 use monoid::Monoid;
+use one::{Inv, One};
+use zero::Zero;
 
 pub trait Group
 where
@@ -8,20 +12,139 @@ where
     fn inverse(x: Self::S) -> Self::S;
 }
 
-macro_rules! impl_add {
-    ($t: ty) => {
-        impl Group for AddMagma<$t> {
+#[doc(hidden)]
+pub use monoid;
+
+/// `define_monoid!` with an additional `inverse`, implementing `Group` as well.
+/// ```
+/// use group::{define_group, Group};
+/// use magma::Magma;
+///
+/// define_group! {
+///     /// addition modulo 7.
+///     pub Add7: u8,
+///     identity: 0,
+///     operation: |a, b| (a + b) % 7,
+///     inverse: |x| (7 - x) % 7,
+///     commutative,
+/// }
+///
+/// assert_eq!(Add7::binary_operation(5, 4), 2);
+/// assert_eq!(Add7::inverse(3), 4);
+/// ```
+#[macro_export]
+macro_rules! define_group {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident: $t:ty,
+        identity: $identity:expr,
+        operation: |$lhs:pat_param, $rhs:pat_param| $op:expr,
+        inverse: |$x:pat_param| $inv:expr
+        $(, $commutative:ident)? $(,)?
+    ) => {
+        $crate::monoid::define_monoid! {
+            $(#[$attr])*
+            $vis $name: $t,
+            identity: $identity,
+            operation: |$lhs, $rhs| $op,
+            $($commutative,)?
+        }
+
+        impl $crate::Group for $name {
+            fn inverse($x: Self::S) -> Self::S {
+                $inv
+            }
+        }
+    };
+}
+
+impl<T: Add<Output = T> + Zero + Neg<Output = T>> Group for AddMagma<T> {
+    fn inverse(x: Self::S) -> Self::S {
+        -x
+    }
+}
+
+/// the multiplicative group of the nonzero elements.
+impl<T: Mul<Output = T> + One + Inv> Group for MulMagma<T> {
+    fn inverse(x: Self::S) -> Self::S {
+        x.inv()
+    }
+}
+
+impl<M1: Group, M2: Group> Group for Pair<M1, M2> {
+    fn inverse(x: Self::S) -> Self::S {
+        (M1::inverse(x.0), M2::inverse(x.1))
+    }
+}
+
+impl<M: Group> Group for Rev<M> {
+    fn inverse(x: Self::S) -> Self::S {
+        M::inverse(x)
+    }
+}
+
+/// the group of the maps with a != 0.
+impl<T> Group for Affine<T>
+where
+    T: Clone + Add<Output = T> + Mul<Output = T> + Neg<Output = T> + Zero + Inv,
+{
+    fn inverse(x: Self::S) -> Self::S {
+        let a = x.0.inv();
+        (a.clone(), -(x.1 * a))
+    }
+}
+
+macro_rules! impl_integer {
+    ($t:ty) => {
+        /// every element is its own inverse.
+        impl Group for XorMagma<$t> {
             fn inverse(x: Self::S) -> Self::S {
-                -x
+                x
+            }
+        }
+
+        impl Group for WrappingAddMagma<$t> {
+            fn inverse(x: Self::S) -> Self::S {
+                x.wrapping_neg()
             }
         }
     };
 }
 
-impl_add!(i8);
-impl_add!(i16);
-impl_add!(i32);
-impl_add!(i64);
-impl_add!(i128);
-impl_add!(f32);
-impl_add!(f64);
+impl_integer!(usize);
+impl_integer!(i8);
+impl_integer!(i16);
+impl_integer!(i32);
+impl_integer!(i64);
+impl_integer!(i128);
+impl_integer!(u8);
+impl_integer!(u16);
+impl_integer!(u32);
+impl_integer!(u64);
+impl_integer!(u128);
+
+#[cfg(test)]
+mod test {
+    use magma::{AddMagma, Affine, Pair, Rev, XorMagma};
+
+    use crate::Group;
+
+    fn check<G: Group>(xs: &[G::S])
+    where
+        G::S: Clone + PartialEq + std::fmt::Debug,
+    {
+        for x in xs {
+            let inv = G::inverse(x.clone());
+            assert_eq!(G::binary_operation(x.clone(), inv.clone()), G::identity());
+            assert_eq!(G::binary_operation(inv, x.clone()), G::identity());
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        check::<XorMagma<u32>>(&[0, 5, 12345]);
+        check::<Pair<AddMagma<i64>, XorMagma<u8>>>(&[(3, 7), (-5, 255)]);
+        check::<Affine<f64>>(&[(2.0, 3.0), (-0.5, 1.0), (4.0, -8.0)]);
+        check::<Rev<Affine<f64>>>(&[(2.0, 3.0), (0.25, 1.0)]);
+    }
+}
@@ -0,0 +1,194 @@
+use convolution::convolution;
+use modint::StaticModInt;
+use semiring::{AddMul, Field, Semiring};
+
+/// shortest linear recurrence s\[i\] = sum_{j=1}^{d} c\[j-1\] s\[i-j\] satisfied by `s`.
+/// return c.
+/// ```
+/// use linear_recurrence::berlekamp_massey;
+/// use modint::ModInt998244353 as Mint;
+/// use semiring::AddMul;
+///
+/// let s = [1, 1, 2, 3, 5, 8].map(Mint::new);
+/// assert_eq!(berlekamp_massey::<AddMul<Mint>>(&s), [1, 1].map(Mint::new));
+/// ```
+pub fn berlekamp_massey<F>(s: &[F::S]) -> Vec<F::S>
+where
+    F: Field,
+    F::S: PartialEq,
+{
+    let mut c = vec![F::one()];
+    let mut b = vec![F::one()];
+    let mut len = 0;
+    let mut shift = 1;
+    let mut last = F::one();
+    for n in 0..s.len() {
+        let d = (0..=len).fold(F::zero(), |acc, i| {
+            F::add(acc, F::mul(c[i].clone(), s[n - i].clone()))
+        });
+        if d == F::zero() {
+            shift += 1;
+            continue;
+        }
+        let coef = F::div(d.clone(), last.clone());
+        let prev = c.clone();
+        if c.len() < b.len() + shift {
+            c.resize(b.len() + shift, F::zero());
+        }
+        for (i, x) in b.iter().enumerate() {
+            c[i + shift] = F::sub(c[i + shift].clone(), F::mul(coef.clone(), x.clone()));
+        }
+        if 2 * len <= n {
+            len = n + 1 - len;
+            b = prev;
+            last = d;
+            shift = 1;
+        } else {
+            shift += 1;
+        }
+    }
+    c.resize(len + 1, F::zero());
+    c.into_iter().skip(1).map(F::neg).collect()
+}
+
+fn mul_naive<R: Semiring>(a: &[R::S], b: &[R::S]) -> Vec<R::S> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut res = vec![R::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            res[i + j] = R::add(res[i + j].clone(), R::mul(x.clone(), y.clone()));
+        }
+    }
+    res
+}
+
+/// \[x^n\] p(x) / q(x), where deg p < deg q and q\[0\] is invertible.
+fn bostan_mori<F, Mul>(p: Vec<F::S>, q: Vec<F::S>, n: u64, mul: Mul) -> F::S
+where
+    F: Field,
+    Mul: Fn(&[F::S], &[F::S]) -> Vec<F::S>,
+{
+    let mut p = p;
+    let mut q = q;
+    let mut n = n;
+    while n > 0 {
+        let q_neg = q
+            .iter()
+            .enumerate()
+            .map(|(i, x)| {
+                if i % 2 == 0 {
+                    x.clone()
+                } else {
+                    F::neg(x.clone())
+                }
+            })
+            .collect::<Vec<_>>();
+        let u = mul(&p, &q_neg);
+        let v = mul(&q, &q_neg);
+        p = u.into_iter().skip((n % 2) as usize).step_by(2).collect();
+        q = v.into_iter().step_by(2).collect();
+        n /= 2;
+    }
+    match p.into_iter().next() {
+        Some(x) => F::div(x, q[0].clone()),
+        None => F::zero(),
+    }
+}
+
+/// (p, q) with sum_i a_i x^i = p(x) / q(x) for the sequence given by `rec` and `init`.
+fn rational_form<F, Mul>(rec: &[F::S], init: &[F::S], mul: &Mul) -> (Vec<F::S>, Vec<F::S>)
+where
+    F: Field,
+    Mul: Fn(&[F::S], &[F::S]) -> Vec<F::S>,
+{
+    assert!(rec.len() <= init.len());
+    let d = rec.len();
+    let mut q = Vec::with_capacity(d + 1);
+    q.push(F::one());
+    q.extend(rec.iter().map(|x| F::neg(x.clone())));
+    let mut p = mul(&init[..d], &q);
+    p.truncate(d);
+    (p, q)
+}
+
+/// n-th term of a\[i\] = sum_{j=1}^{d} rec\[j-1\] a\[i-j\] with a\[0..d\] = init\[0..d\],
+/// in O(d^2 log n).
+/// ```
+/// use linear_recurrence::nth_term;
+/// use modint::ModInt1000000007 as Mint;
+/// use semiring::AddMul;
+///
+/// let rec = [1, 1].map(Mint::new);
+/// let init = [0, 1].map(Mint::new);
+/// assert_eq!(nth_term::<AddMul<Mint>>(&rec, &init, 1000), Mint::new(517691607));
+/// ```
+pub fn nth_term<F: Field>(rec: &[F::S], init: &[F::S], n: u64) -> F::S {
+    if (n as usize) < rec.len() {
+        return init[n as usize].clone();
+    }
+    let (p, q) = rational_form::<F, _>(rec, init, &mul_naive::<F>);
+    bostan_mori::<F, _>(p, q, n, mul_naive::<F>)
+}
+
+/// same as `nth_term` modulo an NTT-friendly prime, in O(d log d log n).
+pub fn nth_term_ntt<const M: u32>(
+    rec: &[StaticModInt<M>],
+    init: &[StaticModInt<M>],
+    n: u64,
+) -> StaticModInt<M> {
+    if (n as usize) < rec.len() {
+        return init[n as usize];
+    }
+    let (p, q) = rational_form::<AddMul<StaticModInt<M>>, _>(rec, init, &convolution);
+    bostan_mori::<AddMul<StaticModInt<M>>, _>(p, q, n, convolution)
+}
+
+#[cfg(test)]
+mod test {
+    use modint::{ModInt998244353 as Mint, StaticModInt};
+    use semiring::AddMul;
+
+    use crate::{berlekamp_massey, nth_term, nth_term_ntt};
+
+    #[test]
+    fn test_berlekamp_massey() {
+        let rec = [3, 0, 998244352, 5].map(Mint::new);
+        let mut a = [1, 4, 1, 5].map(Mint::new).to_vec();
+        for i in 4..40 {
+            let x = (0..4).map(|j| rec[j] * a[i - j - 1]).sum();
+            a.push(x);
+        }
+        assert_eq!(berlekamp_massey::<AddMul<Mint>>(&a), rec);
+        for n in [0, 3, 4, 39, 100] {
+            let expected = if n < a.len() {
+                a[n]
+            } else {
+                let mut b = a.clone();
+                for i in b.len()..=n {
+                    let x = (0..4).map(|j| rec[j] * b[i - j - 1]).sum();
+                    b.push(x);
+                }
+                b[n]
+            };
+            assert_eq!(nth_term::<AddMul<Mint>>(&rec, &a, n as u64), expected);
+            assert_eq!(nth_term_ntt(&rec, &a, n as u64), expected);
+        }
+        assert!(berlekamp_massey::<AddMul<Mint>>(&[Mint::new(0); 5]).is_empty());
+    }
+
+    #[test]
+    fn test_small_field() {
+        type F7 = StaticModInt<7>;
+        let a = [2, 3, 1, 0, 4, 4, 2, 5, 6, 1].map(F7::new);
+        let rec = berlekamp_massey::<AddMul<F7>>(&a);
+        for i in rec.len()..a.len() {
+            let x = (0..rec.len()).map(|j| rec[j] * a[i - j - 1]).sum::<F7>();
+            assert_eq!(x, a[i]);
+        }
+        for n in 0..a.len() {
+            assert_eq!(nth_term::<AddMul<F7>>(&rec, &a, n as u64), a[n]);
+        }
+    }
+}
@@ -1,4 +1,6 @@
 use std::marker::PhantomData;
+use std::num::{Saturating, Wrapping};
+use std::ops::{Add, Mul};
 
 pub trait Magma {
     type S;
@@ -7,64 +9,404 @@ pub trait Magma {
 
 pub trait Commutative {}
 
+/// `binary_operation(x, x) == x` for every x.
+pub trait Idempotent {}
+
+/// `+` of the type is commutative, which makes `AddMagma<Self>` `Commutative`.
+pub trait CommutativeAdd {}
+/// `*` of the type is commutative, which makes `MulMagma<Self>` `Commutative`.
+pub trait CommutativeMul {}
+
 pub struct MaxMagma<T>(PhantomData<T>);
 pub struct MinMagma<T>(PhantomData<T>);
 pub struct AddMagma<T>(PhantomData<T>);
+pub struct MulMagma<T>(PhantomData<T>);
+pub struct XorMagma<T>(PhantomData<T>);
+pub struct AndMagma<T>(PhantomData<T>);
+pub struct OrMagma<T>(PhantomData<T>);
+/// gcd of the absolute values, always non-negative. gcd(0, 0) = 0.
+/// a monoid on unsigned types only, since gcd(0, x) = |x| for signed x.
+pub struct GcdMagma<T>(PhantomData<T>);
+/// lcm of the absolute values, always non-negative. panics on overflow.
+/// a monoid on unsigned types only, since lcm(1, x) = |x| for signed x.
+pub struct LcmMagma<T>(PhantomData<T>);
+/// `saturating_add` of unsigned integers.
+pub struct SaturatingAddMagma<T>(PhantomData<T>);
+/// `wrapping_add`, i.e. addition modulo 2^bits.
+pub struct WrappingAddMagma<T>(PhantomData<T>);
+/// `checked_add` of unsigned integers on `Option<T>`, where `None` is the absorbing overflow state.
+pub struct CheckedAddMagma<T>(PhantomData<T>);
+
+/// component-wise operation on (`M1::S`, `M2::S`).
+pub struct Pair<M1, M2>(PhantomData<(M1, M2)>);
+/// opposite operation, `M::binary_operation(rhs, lhs)`.
+pub struct Rev<M>(PhantomData<M>);
+/// semigroup `Sg` with `None` adjoined as the identity.
+pub struct OptionMonoid<Sg>(PhantomData<Sg>);
+/// minimum with its index. element is (value, index) and the smaller index wins ties.
+pub struct ArgMin<T>(PhantomData<T>);
+/// maximum with its index. element is (value, index) and the smaller index wins ties.
+pub struct ArgMax<T>(PhantomData<T>);
+/// composition of x -> ax + b applying `lhs` first. element is (a, b).
+pub struct Affine<T>(PhantomData<T>);
+
+impl<T: Ord> Magma for MaxMagma<T> {
+    type S = T;
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        lhs.max(rhs)
+    }
+}
+
+impl<T: Ord> Magma for MinMagma<T> {
+    type S = T;
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        lhs.min(rhs)
+    }
+}
+
+impl<T: Ord> Commutative for MaxMagma<T> {}
+impl<T: Ord> Commutative for MinMagma<T> {}
+impl<T: Ord> Idempotent for MaxMagma<T> {}
+impl<T: Ord> Idempotent for MinMagma<T> {}
+
+macro_rules! impl_bitwise {
+    ($t:ty) => {
+        impl Magma for XorMagma<$t> {
+            type S = $t;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                lhs ^ rhs
+            }
+        }
+
+        impl Magma for AndMagma<$t> {
+            type S = $t;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                lhs & rhs
+            }
+        }
+
+        impl Magma for OrMagma<$t> {
+            type S = $t;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                lhs | rhs
+            }
+        }
+        impl Commutative for XorMagma<$t> {}
+        impl Commutative for AndMagma<$t> {}
+        impl Commutative for OrMagma<$t> {}
+        impl Idempotent for AndMagma<$t> {}
+        impl Idempotent for OrMagma<$t> {}
+    };
+}
 
-macro_rules! impl_max_min {
+macro_rules! impl_gcd_lcm {
+    (signed $t:ty, $u:ty) => {
+        impl Magma for GcdMagma<$t> {
+            type S = $t;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                let g = GcdMagma::<$u>::binary_operation(lhs.unsigned_abs(), rhs.unsigned_abs());
+                <$t>::try_from(g).expect("gcd overflow")
+            }
+        }
+
+        impl Magma for LcmMagma<$t> {
+            type S = $t;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                let l = LcmMagma::<$u>::binary_operation(lhs.unsigned_abs(), rhs.unsigned_abs());
+                <$t>::try_from(l).expect("lcm overflow")
+            }
+        }
+        impl Commutative for GcdMagma<$t> {}
+        impl Commutative for LcmMagma<$t> {}
+    };
     ($t:ty) => {
-        impl Magma for MaxMagma<$t> {
+        impl Magma for GcdMagma<$t> {
             type S = $t;
             fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
-                lhs.max(rhs)
+                let (mut a, mut b) = (lhs, rhs);
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                a
             }
         }
 
-        impl Magma for MinMagma<$t> {
+        impl Magma for LcmMagma<$t> {
             type S = $t;
             fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
-                lhs.min(rhs)
+                if lhs == 0 || rhs == 0 {
+                    return 0;
+                }
+                let g = GcdMagma::<$t>::binary_operation(lhs, rhs);
+                (lhs / g).checked_mul(rhs).expect("lcm overflow")
             }
         }
-        impl Commutative for MaxMagma<$t> {}
-        impl Commutative for MinMagma<$t> {}
+        impl Commutative for GcdMagma<$t> {}
+        impl Commutative for LcmMagma<$t> {}
+        impl Idempotent for GcdMagma<$t> {}
+        impl Idempotent for LcmMagma<$t> {}
     };
 }
 
-impl_max_min!(usize);
-impl_max_min!(i8);
-impl_max_min!(i16);
-impl_max_min!(i32);
-impl_max_min!(i64);
-impl_max_min!(i128);
-impl_max_min!(u8);
-impl_max_min!(u16);
-impl_max_min!(u32);
-impl_max_min!(u64);
-impl_max_min!(u128);
-
-macro_rules! impl_add {
-    ($t: ty, $zero:tt) => {
-        impl Magma for AddMagma<$t> {
+macro_rules! impl_overflow {
+    ($t:ty) => {
+        impl Magma for SaturatingAddMagma<$t> {
             type S = $t;
             fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
-                lhs + rhs
+                lhs.saturating_add(rhs)
             }
         }
-        impl Commutative for AddMagma<$t> {}
+
+        impl Magma for CheckedAddMagma<$t> {
+            type S = Option<$t>;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                lhs?.checked_add(rhs?)
+            }
+        }
+        impl Commutative for SaturatingAddMagma<$t> {}
+        impl Commutative for CheckedAddMagma<$t> {}
     };
 }
 
-impl_add!(usize, 0);
-impl_add!(i8, 0);
-impl_add!(i16, 0);
-impl_add!(i32, 0);
-impl_add!(i64, 0);
-impl_add!(i128, 0);
-impl_add!(u8, 0);
-impl_add!(u16, 0);
-impl_add!(u32, 0);
-impl_add!(u64, 0);
-impl_add!(u128, 0);
-impl_add!(f32, 0.0);
-impl_add!(f64, 0.0);
+macro_rules! impl_wrapping {
+    ($t:ty) => {
+        impl Magma for WrappingAddMagma<$t> {
+            type S = $t;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                lhs.wrapping_add(rhs)
+            }
+        }
+        impl Commutative for WrappingAddMagma<$t> {}
+    };
+}
+
+impl_wrapping!(usize);
+impl_wrapping!(i8);
+impl_wrapping!(i16);
+impl_wrapping!(i32);
+impl_wrapping!(i64);
+impl_wrapping!(i128);
+impl_wrapping!(u8);
+impl_wrapping!(u16);
+impl_wrapping!(u32);
+impl_wrapping!(u64);
+impl_wrapping!(u128);
+
+impl_overflow!(usize);
+impl_overflow!(u8);
+impl_overflow!(u16);
+impl_overflow!(u32);
+impl_overflow!(u64);
+impl_overflow!(u128);
+
+impl_bitwise!(usize);
+impl_bitwise!(i8);
+impl_bitwise!(i16);
+impl_bitwise!(i32);
+impl_bitwise!(i64);
+impl_bitwise!(i128);
+impl_bitwise!(u8);
+impl_bitwise!(u16);
+impl_bitwise!(u32);
+impl_bitwise!(u64);
+impl_bitwise!(u128);
+
+impl_gcd_lcm!(usize);
+impl_gcd_lcm!(u8);
+impl_gcd_lcm!(u16);
+impl_gcd_lcm!(u32);
+impl_gcd_lcm!(u64);
+impl_gcd_lcm!(u128);
+impl_gcd_lcm!(signed i8, u8);
+impl_gcd_lcm!(signed i16, u16);
+impl_gcd_lcm!(signed i32, u32);
+impl_gcd_lcm!(signed i64, u64);
+impl_gcd_lcm!(signed i128, u128);
+
+impl<T: Add<Output = T>> Magma for AddMagma<T> {
+    type S = T;
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        lhs + rhs
+    }
+}
+impl<T: Add<Output = T> + CommutativeAdd> Commutative for AddMagma<T> {}
+
+impl<T: Mul<Output = T>> Magma for MulMagma<T> {
+    type S = T;
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        lhs * rhs
+    }
+}
+impl<T: Mul<Output = T> + CommutativeMul> Commutative for MulMagma<T> {}
+
+macro_rules! impl_commutative {
+    ($t:ty) => {
+        impl CommutativeAdd for $t {}
+        impl CommutativeMul for $t {}
+    };
+}
+
+impl_commutative!(usize);
+impl_commutative!(i8);
+impl_commutative!(i16);
+impl_commutative!(i32);
+impl_commutative!(i64);
+impl_commutative!(i128);
+impl_commutative!(u8);
+impl_commutative!(u16);
+impl_commutative!(u32);
+impl_commutative!(u64);
+impl_commutative!(u128);
+impl_commutative!(f32);
+impl_commutative!(f64);
+impl_commutative!(Wrapping<usize>);
+impl_commutative!(Wrapping<i8>);
+impl_commutative!(Wrapping<i16>);
+impl_commutative!(Wrapping<i32>);
+impl_commutative!(Wrapping<i64>);
+impl_commutative!(Wrapping<i128>);
+impl_commutative!(Wrapping<u8>);
+impl_commutative!(Wrapping<u16>);
+impl_commutative!(Wrapping<u32>);
+impl_commutative!(Wrapping<u64>);
+impl_commutative!(Wrapping<u128>);
+impl_commutative!(Saturating<usize>);
+impl_commutative!(Saturating<i8>);
+impl_commutative!(Saturating<i16>);
+impl_commutative!(Saturating<i32>);
+impl_commutative!(Saturating<i64>);
+impl_commutative!(Saturating<i128>);
+impl_commutative!(Saturating<u8>);
+impl_commutative!(Saturating<u16>);
+impl_commutative!(Saturating<u32>);
+impl_commutative!(Saturating<u64>);
+impl_commutative!(Saturating<u128>);
+
+impl<M1: Magma, M2: Magma> Magma for Pair<M1, M2> {
+    type S = (M1::S, M2::S);
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        (
+            M1::binary_operation(lhs.0, rhs.0),
+            M2::binary_operation(lhs.1, rhs.1),
+        )
+    }
+}
+impl<M1: Commutative, M2: Commutative> Commutative for Pair<M1, M2> {}
+impl<M1: Idempotent, M2: Idempotent> Idempotent for Pair<M1, M2> {}
+
+impl<M: Magma> Magma for Rev<M> {
+    type S = M::S;
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        M::binary_operation(rhs, lhs)
+    }
+}
+impl<M: Commutative> Commutative for Rev<M> {}
+impl<M: Idempotent> Idempotent for Rev<M> {}
+
+impl<Sg: Magma> Magma for OptionMonoid<Sg> {
+    type S = Option<Sg::S>;
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        match (lhs, rhs) {
+            (Some(l), Some(r)) => Some(Sg::binary_operation(l, r)),
+            (l, None) => l,
+            (None, r) => r,
+        }
+    }
+}
+impl<Sg: Commutative> Commutative for OptionMonoid<Sg> {}
+impl<Sg: Idempotent> Idempotent for OptionMonoid<Sg> {}
+
+impl<T: Ord> Magma for ArgMin<T> {
+    type S = (T, usize);
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        lhs.min(rhs)
+    }
+}
+impl<T: Ord> Commutative for ArgMin<T> {}
+impl<T: Ord> Idempotent for ArgMin<T> {}
+
+impl<T: Ord> Magma for ArgMax<T> {
+    type S = (T, usize);
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        if rhs.0 > lhs.0 || (rhs.0 == lhs.0 && rhs.1 < lhs.1) {
+            rhs
+        } else {
+            lhs
+        }
+    }
+}
+impl<T: Ord> Commutative for ArgMax<T> {}
+impl<T: Ord> Idempotent for ArgMax<T> {}
+
+impl<T: Clone + Add<Output = T> + Mul<Output = T>> Magma for Affine<T> {
+    type S = (T, T);
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        (lhs.0 * rhs.0.clone(), lhs.1 * rhs.0 + rhs.1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        AndMagma, CheckedAddMagma, GcdMagma, LcmMagma, Magma, OrMagma, SaturatingAddMagma,
+        WrappingAddMagma, XorMagma,
+    };
+
+    #[test]
+    fn test_bitwise_gcd_lcm() {
+        assert_eq!(XorMagma::<u8>::binary_operation(0b1100, 0b1010), 0b0110);
+        assert_eq!(AndMagma::<i32>::binary_operation(-1, 0b1010), 0b1010);
+        assert_eq!(OrMagma::<usize>::binary_operation(0b1100, 0b1010), 0b1110);
+        assert_eq!(GcdMagma::<u64>::binary_operation(12, 18), 6);
+        assert_eq!(GcdMagma::<u64>::binary_operation(0, 18), 18);
+        assert_eq!(LcmMagma::<u32>::binary_operation(4, 6), 12);
+        assert_eq!(LcmMagma::<u8>::binary_operation(0, 6), 0);
+        assert_eq!(LcmMagma::<u8>::binary_operation(15, 17), 255);
+    }
+
+    #[test]
+    fn test_signed_gcd_lcm() {
+        assert_eq!(GcdMagma::<i64>::binary_operation(-12, 18), 6);
+        assert_eq!(GcdMagma::<i64>::binary_operation(-12, -18), 6);
+        assert_eq!(GcdMagma::<i32>::binary_operation(0, -7), 7);
+        assert_eq!(GcdMagma::<i8>::binary_operation(0, 0), 0);
+        assert_eq!(GcdMagma::<i8>::binary_operation(i8::MIN, 6), 2);
+        assert_eq!(LcmMagma::<i32>::binary_operation(-4, 6), 12);
+        assert_eq!(LcmMagma::<i32>::binary_operation(-4, -6), 12);
+        assert_eq!(LcmMagma::<i16>::binary_operation(-5, 0), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_signed_gcd_overflow() {
+        GcdMagma::<i8>::binary_operation(i8::MIN, 0);
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(SaturatingAddMagma::<u8>::binary_operation(200, 100), 255);
+        assert_eq!(
+            SaturatingAddMagma::<u64>::binary_operation(u64::MAX, 1),
+            u64::MAX
+        );
+        assert_eq!(WrappingAddMagma::<u8>::binary_operation(200, 100), 44);
+        assert_eq!(
+            CheckedAddMagma::<u8>::binary_operation(Some(200), Some(55)),
+            Some(255)
+        );
+        assert_eq!(
+            CheckedAddMagma::<u8>::binary_operation(Some(200), Some(56)),
+            None
+        );
+        assert_eq!(
+            CheckedAddMagma::<u32>::binary_operation(None, Some(0)),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lcm_overflow() {
+        LcmMagma::<u8>::binary_operation(16, 17);
+    }
+}
@@ -0,0 +1,932 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use magma::Magma;
+use modint::{DynamicModInt, ModulusId, StaticModInt};
+use monoid::Monoid;
+use rational::{Integer, Rational};
+use semiring::{Field, Semiring};
+
+/// # Matrix
+/// dense h x w matrix over a semiring `R`.
+/// ```
+/// use matrix::Matrix;
+/// use modint::ModInt998244353 as Mint;
+/// use semiring::AddMul;
+///
+/// let fib = Matrix::<AddMul<Mint>>::from_vec(vec![
+///     vec![Mint::new(1), Mint::new(1)],
+///     vec![Mint::new(1), Mint::new(0)],
+/// ]);
+/// assert_eq!(fib.pow(10)[(0, 1)], Mint::new(55));
+/// ```
+pub struct Matrix<R: Semiring> {
+    h: usize,
+    w: usize,
+    data: Vec<R::S>,
+}
+
+impl<R: Semiring> Matrix<R> {
+    /// zero matrix.
+    pub fn new(h: usize, w: usize) -> Self {
+        Matrix {
+            h,
+            w,
+            data: vec![R::zero(); h * w],
+        }
+    }
+
+    /// every row must have the same length.
+    pub fn from_vec(v: Vec<Vec<R::S>>) -> Self {
+        let h = v.len();
+        let w = v.first().map_or(0, |row| row.len());
+        assert!(v.iter().all(|row| row.len() == w));
+        Matrix {
+            h,
+            w,
+            data: v.into_iter().flatten().collect(),
+        }
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut res = Self::new(n, n);
+        for i in 0..n {
+            res[(i, i)] = R::one();
+        }
+        res
+    }
+
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut res = Self::new(self.w, self.h);
+        for i in 0..self.h {
+            for j in 0..self.w {
+                res[(j, i)] = self[(i, j)].clone();
+            }
+        }
+        res
+    }
+
+    /// self^k for a square matrix, in O(n^3 log k).
+    pub fn pow(&self, k: u64) -> Self {
+        assert_eq!(self.h, self.w);
+        let mut res = Self::identity(self.h);
+        let mut x = self.clone();
+        let mut k = k;
+        while k > 0 {
+            if k & 1 == 1 {
+                res = &res * &x;
+            }
+            k >>= 1;
+            if k > 0 {
+                x = &x * &x;
+            }
+        }
+        res
+    }
+}
+
+impl<R: Semiring> Clone for Matrix<R> {
+    fn clone(&self) -> Self {
+        Matrix {
+            h: self.h,
+            w: self.w,
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<R: Semiring> PartialEq for Matrix<R>
+where
+    R::S: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.h == other.h && self.w == other.w && self.data == other.data
+    }
+}
+
+impl<R: Semiring> Eq for Matrix<R> where R::S: Eq {}
+
+impl<R: Semiring> Debug for Matrix<R>
+where
+    R::S: Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.data.chunks(self.w.max(1)))
+            .finish()
+    }
+}
+
+impl<R: Semiring> Index<(usize, usize)> for Matrix<R> {
+    type Output = R::S;
+    fn index(&self, (i, j): (usize, usize)) -> &R::S {
+        assert!(i < self.h && j < self.w);
+        &self.data[i * self.w + j]
+    }
+}
+
+impl<R: Semiring> IndexMut<(usize, usize)> for Matrix<R> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut R::S {
+        assert!(i < self.h && j < self.w);
+        &mut self.data[i * self.w + j]
+    }
+}
+
+impl<R: Semiring> Add for &Matrix<R> {
+    type Output = Matrix<R>;
+    fn add(self, rhs: Self) -> Matrix<R> {
+        assert!(self.h == rhs.h && self.w == rhs.w);
+        Matrix {
+            h: self.h,
+            w: self.w,
+            data: self
+                .data
+                .iter()
+                .zip(&rhs.data)
+                .map(|(x, y)| R::add(x.clone(), y.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl<R: Semiring> Add for Matrix<R> {
+    type Output = Matrix<R>;
+    fn add(self, rhs: Self) -> Matrix<R> {
+        &self + &rhs
+    }
+}
+
+impl<R: Semiring> Mul for &Matrix<R> {
+    type Output = Matrix<R>;
+    fn mul(self, rhs: Self) -> Matrix<R> {
+        assert_eq!(self.w, rhs.h);
+        let mut res = Matrix::<R>::new(self.h, rhs.w);
+        for i in 0..self.h {
+            for k in 0..self.w {
+                let x = &self[(i, k)];
+                for j in 0..rhs.w {
+                    res[(i, j)] =
+                        R::add(res[(i, j)].clone(), R::mul(x.clone(), rhs[(k, j)].clone()));
+                }
+            }
+        }
+        res
+    }
+}
+
+impl<R: Semiring> Mul for Matrix<R> {
+    type Output = Matrix<R>;
+    fn mul(self, rhs: Self) -> Matrix<R> {
+        &self * &rhs
+    }
+}
+
+/// multiplication of N x N matrices over `R`.
+pub struct MatMul<R, const N: usize>(PhantomData<R>);
+
+impl<R: Semiring, const N: usize> Magma for MatMul<R, N> {
+    type S = Matrix<R>;
+    fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+        &lhs * &rhs
+    }
+}
+
+impl<R: Semiring, const N: usize> Monoid for MatMul<R, N> {
+    fn identity() -> Self::S {
+        Matrix::identity(N)
+    }
+}
+
+/// element usable as a pivot in Gaussian elimination.
+pub trait Pivot {
+    /// whether `self` is regarded as zero.
+    fn is_zero(&self) -> bool;
+
+    /// among the nonzero candidates, the first one with the largest weight becomes the pivot.
+    fn weight(&self) -> f64 {
+        0.0
+    }
+}
+
+impl<const M: u32> Pivot for StaticModInt<M> {
+    fn is_zero(&self) -> bool {
+        self.val() == 0
+    }
+}
+
+impl<I: ModulusId> Pivot for DynamicModInt<I> {
+    fn is_zero(&self) -> bool {
+        self.val() == 0
+    }
+}
+
+impl<T: Integer> Pivot for Rational<T> {
+    fn is_zero(&self) -> bool {
+        self.num() == T::zero()
+    }
+}
+
+const EPS: f64 = 1e-9;
+
+impl Pivot for f64 {
+    fn is_zero(&self) -> bool {
+        self.abs() < EPS
+    }
+
+    fn weight(&self) -> f64 {
+        self.abs()
+    }
+}
+
+impl Pivot for f32 {
+    fn is_zero(&self) -> bool {
+        (self.abs() as f64) < EPS
+    }
+
+    fn weight(&self) -> f64 {
+        self.abs() as f64
+    }
+}
+
+/// (a particular solution, a basis of the kernel) of a linear system.
+pub type Solution<T> = (Vec<T>, Vec<Vec<T>>);
+
+impl<F: Field> Matrix<F>
+where
+    F::S: Pivot,
+{
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for j in 0..self.w {
+            self.data.swap(a * self.w + j, b * self.w + j);
+        }
+    }
+
+    /// reduce the first `cols` columns to reduced row echelon form.
+    /// return the pivot columns and the product of the pivots with the sign of the row swaps.
+    fn reduce(&mut self, cols: usize) -> (Vec<usize>, F::S) {
+        let mut pivots = vec![];
+        let mut det = F::one();
+        for c in 0..cols {
+            let r = pivots.len();
+            if r == self.h {
+                break;
+            }
+            let mut best: Option<usize> = None;
+            for i in r..self.h {
+                let x = &self[(i, c)];
+                if x.is_zero() {
+                    continue;
+                }
+                match best {
+                    Some(b) if x.weight() <= self[(b, c)].weight() => {}
+                    _ => best = Some(i),
+                }
+            }
+            let Some(p) = best else {
+                continue;
+            };
+            if p != r {
+                self.swap_rows(p, r);
+                det = F::neg(det);
+            }
+            let pivot = self[(r, c)].clone();
+            det = F::mul(det, pivot.clone());
+            let inv = F::inv(pivot);
+            for j in 0..self.w {
+                self[(r, j)] = F::mul(self[(r, j)].clone(), inv.clone());
+            }
+            for i in 0..self.h {
+                if i == r || self[(i, c)].is_zero() {
+                    continue;
+                }
+                let f = self[(i, c)].clone();
+                for j in 0..self.w {
+                    let x = F::mul(f.clone(), self[(r, j)].clone());
+                    self[(i, j)] = F::sub(self[(i, j)].clone(), x);
+                }
+            }
+            pivots.push(c);
+        }
+        (pivots, det)
+    }
+
+    /// transform into reduced row echelon form and return the rank, in O(h w min(h, w)).
+    pub fn row_reduce(&mut self) -> usize {
+        self.reduce(self.w).0.len()
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce()
+    }
+
+    /// determinant of a square matrix in O(n^3).
+    pub fn det(&self) -> F::S {
+        assert_eq!(self.h, self.w);
+        let (pivots, det) = self.clone().reduce(self.w);
+        if pivots.len() < self.h {
+            F::zero()
+        } else {
+            det
+        }
+    }
+
+    /// inverse of a square matrix in O(n^3). None if it is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut aug = Self::new(n, 2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                aug[(i, j)] = self[(i, j)].clone();
+            }
+            aug[(i, n + i)] = F::one();
+        }
+        if aug.reduce(n).0.len() < n {
+            return None;
+        }
+        let mut res = Self::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                res[(i, j)] = aug[(i, n + j)].clone();
+            }
+        }
+        Some(res)
+    }
+
+    /// solutions of self x = b as (a particular solution, a basis of the kernel).
+    /// None if there is no solution.
+    /// ```
+    /// use matrix::Matrix;
+    /// use modint::ModInt998244353 as Mint;
+    /// use semiring::AddMul;
+    ///
+    /// let a = Matrix::<AddMul<Mint>>::from_vec(vec![
+    ///     vec![Mint::new(1), Mint::new(2), Mint::new(3)],
+    ///     vec![Mint::new(2), Mint::new(4), Mint::new(6)],
+    /// ]);
+    /// let (x, kernel) = a.solve(&[Mint::new(6), Mint::new(12)]).unwrap();
+    /// assert_eq!(x, [6, 0, 0].map(Mint::new));
+    /// assert_eq!(kernel.len(), 2);
+    /// assert!(a.solve(&[Mint::new(6), Mint::new(11)]).is_none());
+    /// ```
+    pub fn solve(&self, b: &[F::S]) -> Option<Solution<F::S>> {
+        assert_eq!(self.h, b.len());
+        let (h, w) = (self.h, self.w);
+        let mut aug = Self::new(h, w + 1);
+        for i in 0..h {
+            for j in 0..w {
+                aug[(i, j)] = self[(i, j)].clone();
+            }
+            aug[(i, w)] = b[i].clone();
+        }
+        let (pivots, _) = aug.reduce(w);
+        if (pivots.len()..h).any(|i| !aug[(i, w)].is_zero()) {
+            return None;
+        }
+        let mut x = vec![F::zero(); w];
+        for (i, &c) in pivots.iter().enumerate() {
+            x[c] = aug[(i, w)].clone();
+        }
+        let mut is_pivot = vec![false; w];
+        for &c in &pivots {
+            is_pivot[c] = true;
+        }
+        let kernel = (0..w)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut v = vec![F::zero(); w];
+                v[f] = F::one();
+                for (i, &c) in pivots.iter().enumerate() {
+                    v[c] = F::neg(aug[(i, f)].clone());
+                }
+                v
+            })
+            .collect();
+        Some((x, kernel))
+    }
+}
+
+/// # Matrix over GF(2)
+/// each row is a bitset of u64.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitMatrix {
+    h: usize,
+    w: usize,
+    data: Vec<Vec<u64>>,
+}
+
+impl BitMatrix {
+    /// zero matrix.
+    pub fn new(h: usize, w: usize) -> Self {
+        BitMatrix {
+            h,
+            w,
+            data: vec![vec![0; w.div_ceil(64)]; h],
+        }
+    }
+
+    /// every row must have the same length.
+    pub fn from_vec(v: &[Vec<bool>]) -> Self {
+        let w = v.first().map_or(0, |row| row.len());
+        assert!(v.iter().all(|row| row.len() == w));
+        let mut res = Self::new(v.len(), w);
+        for (i, row) in v.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                res.set(i, j, x);
+            }
+        }
+        res
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut res = Self::new(n, n);
+        for i in 0..n {
+            res.set(i, i, true);
+        }
+        res
+    }
+
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        assert!(i < self.h && j < self.w);
+        self.data[i][j / 64] >> (j % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, j: usize, x: bool) {
+        assert!(i < self.h && j < self.w);
+        if x {
+            self.data[i][j / 64] |= 1 << (j % 64);
+        } else {
+            self.data[i][j / 64] &= !(1 << (j % 64));
+        }
+    }
+
+    /// reduce the first `cols` columns to reduced row echelon form and return the pivot columns.
+    fn reduce(&mut self, cols: usize) -> Vec<usize> {
+        let mut pivots = vec![];
+        for c in 0..cols {
+            let r = pivots.len();
+            if r == self.h {
+                break;
+            }
+            let Some(p) = (r..self.h).find(|&i| self.get(i, c)) else {
+                continue;
+            };
+            self.data.swap(p, r);
+            let pivot_row = self.data[r].clone();
+            for i in 0..self.h {
+                if i != r && self.get(i, c) {
+                    for (x, y) in self.data[i].iter_mut().zip(&pivot_row) {
+                        *x ^= y;
+                    }
+                }
+            }
+            pivots.push(c);
+        }
+        pivots
+    }
+
+    /// transform into reduced row echelon form and return the rank, in O(h w min(h, w) / 64).
+    pub fn row_reduce(&mut self) -> usize {
+        self.reduce(self.w).len()
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce()
+    }
+
+    /// determinant of a square matrix.
+    pub fn det(&self) -> bool {
+        assert_eq!(self.h, self.w);
+        self.rank() == self.h
+    }
+
+    /// inverse of a square matrix. None if it is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        assert_eq!(self.h, self.w);
+        let n = self.h;
+        let mut aug = Self::new(n, 2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                aug.set(i, j, self.get(i, j));
+            }
+            aug.set(i, n + i, true);
+        }
+        if aug.reduce(n).len() < n {
+            return None;
+        }
+        let mut res = Self::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                res.set(i, j, aug.get(i, n + j));
+            }
+        }
+        Some(res)
+    }
+
+    /// solutions of self x = b as (a particular solution, a basis of the kernel).
+    /// None if there is no solution.
+    pub fn solve(&self, b: &[bool]) -> Option<Solution<bool>> {
+        assert_eq!(self.h, b.len());
+        let (h, w) = (self.h, self.w);
+        let mut aug = Self::new(h, w + 1);
+        for (i, &x) in b.iter().enumerate() {
+            aug.data[i][..self.data[i].len()].copy_from_slice(&self.data[i]);
+            aug.set(i, w, x);
+        }
+        let pivots = aug.reduce(w);
+        if (pivots.len()..h).any(|i| aug.get(i, w)) {
+            return None;
+        }
+        let mut x = vec![false; w];
+        for (i, &c) in pivots.iter().enumerate() {
+            x[c] = aug.get(i, w);
+        }
+        let mut is_pivot = vec![false; w];
+        for &c in &pivots {
+            is_pivot[c] = true;
+        }
+        let kernel = (0..w)
+            .filter(|&f| !is_pivot[f])
+            .map(|f| {
+                let mut v = vec![false; w];
+                v[f] = true;
+                for (i, &c) in pivots.iter().enumerate() {
+                    v[c] = aug.get(i, f);
+                }
+                v
+            })
+            .collect();
+        Some((x, kernel))
+    }
+}
+
+/// # XOR Basis
+/// basis of the span of u64 values over GF(2), kept in reduced form.
+/// ```
+/// use matrix::XorBasis;
+///
+/// let mut basis = XorBasis::new();
+/// assert!(basis.insert(0b110));
+/// assert!(basis.insert(0b011));
+/// assert!(!basis.insert(0b101));
+/// assert!(basis.contains(0b101));
+/// assert!(!basis.contains(0b001));
+/// assert_eq!(basis.max_xor(), 0b110);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct XorBasis {
+    /// distinct highest bits in decreasing order.
+    basis: Vec<u64>,
+}
+
+impl XorBasis {
+    pub fn new() -> Self {
+        XorBasis { basis: vec![] }
+    }
+
+    fn reduce(&self, x: u64) -> u64 {
+        self.basis.iter().fold(x, |x, &b| x.min(x ^ b))
+    }
+
+    /// add `x` to the span. return false if it was already spanned.
+    pub fn insert(&mut self, x: u64) -> bool {
+        let x = self.reduce(x);
+        if x == 0 {
+            return false;
+        }
+        let top = 63 - x.leading_zeros();
+        for b in self.basis.iter_mut() {
+            if *b >> top & 1 == 1 {
+                *b ^= x;
+            }
+        }
+        let pos = self.basis.partition_point(|&b| b > x);
+        self.basis.insert(pos, x);
+        true
+    }
+
+    /// whether `x` is the XOR of some subset.
+    pub fn contains(&self, x: u64) -> bool {
+        self.reduce(x) == 0
+    }
+
+    /// maximum XOR of a subset.
+    pub fn max_xor(&self) -> u64 {
+        self.basis.iter().fold(0, |acc, &b| acc ^ b)
+    }
+
+    /// dimension of the span.
+    pub fn len(&self) -> usize {
+        self.basis.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.basis.is_empty()
+    }
+
+    pub fn basis(&self) -> &[u64] {
+        &self.basis
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use magma::Magma;
+    use modint::{ModInt998244353 as Mint, StaticModInt};
+    use monoid::Monoid;
+    use rational::Rational;
+    use semiring::{AddMul, MinPlus};
+
+    use crate::{BitMatrix, MatMul, Matrix, XorBasis};
+
+    /// deterministic pseudo random sequence.
+    fn sequence(n: usize, seed: u64) -> Vec<u64> {
+        let mut x = seed;
+        (0..n)
+            .map(|_| {
+                x ^= x << 13;
+                x ^= x >> 7;
+                x ^= x << 17;
+                x
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_add_mul() {
+        type M = Matrix<AddMul<i64>>;
+        let a = M::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        let b = a.transpose();
+        assert_eq!(b, M::from_vec(vec![vec![1, 4], vec![2, 5], vec![3, 6]]));
+        assert_eq!(&a * &b, M::from_vec(vec![vec![14, 32], vec![32, 77]]));
+        assert_eq!(&a + &a, M::from_vec(vec![vec![2, 4, 6], vec![8, 10, 12]]));
+        let c = &b * &a;
+        let mut p = M::identity(3);
+        for k in 0..6 {
+            assert_eq!(c.pow(k), p);
+            p = &p * &c;
+        }
+
+        // a[i] = a[i-1] + 2 a[i-2] + 3 a[i-3]
+        let rec = Matrix::<AddMul<Mint>>::from_vec(vec![
+            vec![Mint::new(1), Mint::new(2), Mint::new(3)],
+            vec![Mint::new(1), Mint::new(0), Mint::new(0)],
+            vec![Mint::new(0), Mint::new(1), Mint::new(0)],
+        ]);
+        let mut a = vec![Mint::new(1), Mint::new(1), Mint::new(1)];
+        for i in 3..100 {
+            let x = a[i - 1] + a[i - 2] * Mint::new(2) + a[i - 3] * Mint::new(3);
+            a.push(x);
+        }
+        let p = rec.pow(97);
+        let x = p[(0, 0)] * a[2] + p[(0, 1)] * a[1] + p[(0, 2)] * a[0];
+        assert_eq!(x, a[99]);
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        const INF: i64 = i64::MAX;
+        type M = Matrix<MinPlus<i64>>;
+        let n = 4;
+        let edges = [
+            (0, 1, 3),
+            (1, 2, 1),
+            (2, 0, 2),
+            (1, 3, 10),
+            (2, 3, 4),
+            (3, 3, 0),
+        ];
+        let mut g = M::new(n, n);
+        for &(u, v, c) in &edges {
+            g[(u, v)] = c;
+        }
+        assert_eq!(g[(0, 2)], INF);
+        // shortest walk with exactly k edges
+        let mut dp = vec![INF; n];
+        dp[0] = 0;
+        for k in 0..8 {
+            let p = g.pow(k);
+            for (v, &d) in dp.iter().enumerate() {
+                assert_eq!(p[(0, v)], d);
+            }
+            let mut next = vec![INF; n];
+            for &(u, v, c) in &edges {
+                if dp[u] != INF {
+                    next[v] = next[v].min(dp[u] + c);
+                }
+            }
+            dp = next;
+        }
+        type G = MatMul<MinPlus<i64>, 4>;
+        let prod = [g.clone(), g.clone(), g.clone()]
+            .into_iter()
+            .fold(G::identity(), G::binary_operation);
+        assert_eq!(prod, g.pow(3));
+    }
+
+    fn det_naive(a: &Matrix<AddMul<Mint>>, rows: &mut Vec<usize>, used: &mut Vec<bool>) -> Mint {
+        let n = a.height();
+        if rows.len() == n {
+            let mut inv = 0;
+            for i in 0..n {
+                for j in i + 1..n {
+                    if rows[i] > rows[j] {
+                        inv += 1;
+                    }
+                }
+            }
+            let prod = (0..n).map(|j| a[(rows[j], j)]).product::<Mint>();
+            return if inv % 2 == 0 { prod } else { -prod };
+        }
+        let mut res = Mint::new(0);
+        for i in 0..n {
+            if !used[i] {
+                used[i] = true;
+                rows.push(i);
+                res += det_naive(a, rows, used);
+                rows.pop();
+                used[i] = false;
+            }
+        }
+        res
+    }
+
+    #[test]
+    fn test_gauss() {
+        type M = Matrix<AddMul<Mint>>;
+        for seed in 1..30 {
+            let n = seed % 5 + 1;
+            let mut v = sequence(n * n, seed as u64)
+                .into_iter()
+                .map(|x| Mint::new(x % 3))
+                .collect::<Vec<_>>();
+            if seed % 4 == 0 {
+                // make the matrix singular
+                for j in 0..n {
+                    v[j] = v[n * (n - 1) + j] * Mint::new(2);
+                }
+            }
+            let a = M::from_vec(v.chunks(n).map(|row| row.to_vec()).collect());
+            let det = a.det();
+            assert_eq!(det, det_naive(&a, &mut vec![], &mut vec![false; n]));
+            match a.inverse() {
+                Some(inv) => {
+                    assert_eq!(&a * &inv, M::identity(n));
+                    assert_eq!(a.rank(), n);
+                }
+                None => {
+                    assert_eq!(det, Mint::new(0));
+                    assert!(a.rank() < n);
+                }
+            }
+        }
+        for seed in 1..30 {
+            let (h, w) = (seed % 4 + 1, seed % 5 + 1);
+            let v = sequence(h * w + h, seed as u64)
+                .into_iter()
+                .map(|x| Mint::new(x % 2))
+                .collect::<Vec<_>>();
+            let a = M::from_vec(v[..h * w].chunks(w).map(|row| row.to_vec()).collect());
+            let b = &v[h * w..];
+            let mul = |x: &[Mint]| {
+                (0..h)
+                    .map(|i| (0..w).map(|j| a[(i, j)] * x[j]).sum::<Mint>())
+                    .collect::<Vec<_>>()
+            };
+            match a.solve(b) {
+                Some((x, kernel)) => {
+                    assert_eq!(mul(&x), b);
+                    assert_eq!(kernel.len(), w - a.rank());
+                    for k in &kernel {
+                        assert!(mul(k).iter().all(|&y| y == Mint::new(0)));
+                    }
+                }
+                None => {
+                    let mut aug = M::new(h, w + 1);
+                    for i in 0..h {
+                        for j in 0..w {
+                            aug[(i, j)] = a[(i, j)];
+                        }
+                        aug[(i, w)] = b[i];
+                    }
+                    assert_eq!(aug.rank(), a.rank() + 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rational() {
+        type Q = Rational<i64>;
+        let a = Matrix::<AddMul<Q>>::from_vec(vec![
+            vec![Q::from(2), Q::from(1)],
+            vec![Q::from(1), Q::from(3)],
+        ]);
+        assert_eq!(a.det(), Q::from(5));
+        let inv = a.inverse().unwrap();
+        assert_eq!(inv[(0, 0)], Q::new(3, 5));
+        assert_eq!(inv[(0, 1)], Q::new(-1, 5));
+        assert_eq!(&a * &inv, Matrix::identity(2));
+    }
+
+    #[test]
+    fn test_float() {
+        let a = Matrix::<AddMul<f64>>::from_vec(vec![
+            vec![1e-12, 1.0, 2.0],
+            vec![1.0, 1.0, 1.0],
+            vec![2.0, 1.0, 3.0],
+        ]);
+        let (x, kernel) = a.solve(&[3.0, 3.0, 6.0]).unwrap();
+        assert!(kernel.is_empty());
+        for (x, y) in x.iter().zip([1.0, 1.0, 1.0]) {
+            assert!((x - y).abs() < 1e-9);
+        }
+        assert!((a.det() - (-3.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_gf2() {
+        type F2 = StaticModInt<2>;
+        for seed in 1..40 {
+            let (h, w) = (seed % 7 + 1, seed % 9 + 1);
+            let v = sequence(h * w + h, seed as u64)
+                .into_iter()
+                .map(|x| x % 3 == 0)
+                .collect::<Vec<_>>();
+            let rows = v[..h * w]
+                .chunks(w)
+                .map(|row| row.to_vec())
+                .collect::<Vec<_>>();
+            let a = BitMatrix::from_vec(&rows);
+            let b = Matrix::<AddMul<F2>>::from_vec(
+                rows.iter()
+                    .map(|row| row.iter().map(|&x| F2::new(x as u32)).collect())
+                    .collect(),
+            );
+            assert_eq!(a.rank(), b.rank());
+            let rhs = &v[h * w..];
+            let res = a.solve(rhs);
+            let expected = b.solve(&rhs.iter().map(|&x| F2::new(x as u32)).collect::<Vec<_>>());
+            assert_eq!(res.is_some(), expected.is_some());
+            if let (Some((x, kernel)), Some((y, kernel2))) = (res, expected) {
+                assert_eq!(x.iter().map(|&x| F2::new(x as u32)).collect::<Vec<_>>(), y);
+                assert_eq!(kernel.len(), kernel2.len());
+            }
+            if h == w {
+                assert_eq!(a.det(), b.det() == F2::new(1));
+                if let Some(inv) = a.inverse() {
+                    let mut prod = BitMatrix::new(h, h);
+                    for i in 0..h {
+                        for j in 0..h {
+                            prod.set(
+                                i,
+                                j,
+                                (0..h).filter(|&k| a.get(i, k) && inv.get(k, j)).count() % 2 == 1,
+                            );
+                        }
+                    }
+                    assert_eq!(prod, BitMatrix::identity(h));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_xor_basis() {
+        let v = sequence(6, 7)
+            .into_iter()
+            .map(|x| x % 64)
+            .collect::<Vec<_>>();
+        let mut basis = XorBasis::new();
+        let mut span = vec![0u64];
+        for &x in &v {
+            let inserted = basis.insert(x);
+            assert_eq!(inserted, !span.contains(&x));
+            if inserted {
+                span = span.iter().flat_map(|&y| [y, y ^ x]).collect();
+            }
+        }
+        assert_eq!(1 << basis.len(), span.len());
+        for x in 0..64 {
+            assert_eq!(basis.contains(x), span.contains(&x));
+        }
+        assert_eq!(basis.max_xor(), *span.iter().max().unwrap());
+    }
+}
@@ -0,0 +1,489 @@
+use std::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    iter::{Product, Sum},
+    marker::PhantomData,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+    sync::atomic::{self, AtomicU32},
+};
+
+use magma::{CommutativeAdd, CommutativeMul};
+use one::{Inv, One};
+use zero::Zero;
+
+/// common interface of `StaticModInt` and `DynamicModInt`.
+pub trait ModIntBase:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + Default
+    + Display
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Zero
+    + One
+    + Inv
+{
+    fn modulus() -> u32;
+
+    /// `v` must be less than the modulus.
+    fn raw(v: u32) -> Self;
+
+    fn val(self) -> u32;
+
+    fn pow(self, n: u64) -> Self {
+        let mut res = Self::raw(1 % Self::modulus());
+        let mut x = self;
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                res *= x;
+            }
+            x *= x;
+            n >>= 1;
+        }
+        res
+    }
+}
+
+/// modular inverse of `a` modulo `m` by the extended Euclidean algorithm.
+/// panics if `gcd(a, m) != 1`.
+fn inv_mod(a: u32, m: u32) -> u32 {
+    let (mut s, mut t) = (m as i64, a as i64);
+    let (mut m0, mut m1) = (0i64, 1i64);
+    while t != 0 {
+        let u = s / t;
+        s -= t * u;
+        m0 -= m1 * u;
+        std::mem::swap(&mut s, &mut t);
+        std::mem::swap(&mut m0, &mut m1);
+    }
+    assert!(s == 1, "{} is not invertible modulo {}", a, m);
+    m0.rem_euclid(m as i64) as u32
+}
+
+/// error of parsing a string as a decimal integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseModIntError {
+    /// no digits after the optional sign.
+    Empty,
+    /// a character other than an ASCII digit after the optional sign.
+    InvalidDigit,
+}
+
+impl Display for ParseModIntError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseModIntError::Empty => write!(f, "cannot parse integer from empty string"),
+            ParseModIntError::InvalidDigit => write!(f, "invalid digit found in string"),
+        }
+    }
+}
+
+impl std::error::Error for ParseModIntError {}
+
+fn check_digits(digits: &str) -> Result<(), ParseModIntError> {
+    if digits.is_empty() {
+        Err(ParseModIntError::Empty)
+    } else if !digits.bytes().all(|c| c.is_ascii_digit()) {
+        Err(ParseModIntError::InvalidDigit)
+    } else {
+        Ok(())
+    }
+}
+
+/// integer modulo `M` fixed at compile time.
+/// ```
+/// use modint::ModInt998244353;
+///
+/// let a = ModInt998244353::new(3);
+/// let b = ModInt998244353::new(-1);
+/// assert_eq!(a + b, ModInt998244353::new(2));
+/// assert_eq!((a / b).val(), 998244350);
+/// assert_eq!(a.pow(998244352), ModInt998244353::new(1));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StaticModInt<const M: u32> {
+    val: u32,
+}
+
+pub type ModInt998244353 = StaticModInt<998244353>;
+pub type ModInt1000000007 = StaticModInt<1000000007>;
+
+impl<const M: u32> StaticModInt<M> {
+    pub fn new<T>(v: T) -> Self
+    where
+        Self: From<T>,
+    {
+        Self::from(v)
+    }
+}
+
+impl<const M: u32> ModIntBase for StaticModInt<M> {
+    fn modulus() -> u32 {
+        M
+    }
+
+    fn raw(v: u32) -> Self {
+        StaticModInt { val: v }
+    }
+
+    fn val(self) -> u32 {
+        self.val
+    }
+}
+
+/// modulus of `DynamicModInt<Self>`, which can be changed at runtime.
+pub trait ModulusId: 'static {
+    fn modulus_cell() -> &'static AtomicU32;
+}
+
+pub enum DefaultId {}
+
+impl ModulusId for DefaultId {
+    fn modulus_cell() -> &'static AtomicU32 {
+        static MODULUS: AtomicU32 = AtomicU32::new(998244353);
+        &MODULUS
+    }
+}
+
+/// integer modulo a value given at runtime.
+/// values created under another modulus must not be mixed.
+/// ```
+/// use modint::ModInt;
+///
+/// ModInt::set_modulus(7);
+/// let a = ModInt::new(10);
+/// assert_eq!(a.val(), 3);
+/// assert_eq!(a.inv(), ModInt::new(5));
+/// ```
+pub struct DynamicModInt<I> {
+    val: u32,
+    __marker: PhantomData<fn() -> I>,
+}
+
+pub type ModInt = DynamicModInt<DefaultId>;
+
+impl<I: ModulusId> DynamicModInt<I> {
+    pub fn new<T>(v: T) -> Self
+    where
+        Self: From<T>,
+    {
+        Self::from(v)
+    }
+
+    pub fn set_modulus(m: u32) {
+        assert!(1 <= m);
+        I::modulus_cell().store(m, atomic::Ordering::Relaxed);
+    }
+}
+
+impl<I: ModulusId> ModIntBase for DynamicModInt<I> {
+    fn modulus() -> u32 {
+        I::modulus_cell().load(atomic::Ordering::Relaxed)
+    }
+
+    fn raw(v: u32) -> Self {
+        DynamicModInt {
+            val: v,
+            __marker: PhantomData,
+        }
+    }
+
+    fn val(self) -> u32 {
+        self.val
+    }
+}
+
+impl<I: ModulusId> Clone for DynamicModInt<I> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I: ModulusId> Copy for DynamicModInt<I> {}
+
+impl<I: ModulusId> PartialEq for DynamicModInt<I> {
+    fn eq(&self, other: &Self) -> bool {
+        self.val == other.val
+    }
+}
+
+impl<I: ModulusId> Eq for DynamicModInt<I> {}
+
+impl<I: ModulusId> PartialOrd for DynamicModInt<I> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I: ModulusId> Ord for DynamicModInt<I> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.val.cmp(&other.val)
+    }
+}
+
+impl<I: ModulusId> Hash for DynamicModInt<I> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.val.hash(state);
+    }
+}
+
+impl<I: ModulusId> Default for DynamicModInt<I> {
+    fn default() -> Self {
+        Self::raw(0)
+    }
+}
+
+/// `$w` is a type wide enough to hold both `$t` and the modulus.
+macro_rules! impl_from {
+    ([$($g:tt)*] $m:ty, $w:ty, $t:ty) => {
+        impl<$($g)*> From<$t> for $m {
+            fn from(v: $t) -> Self {
+                Self::raw((v as $w).rem_euclid(<$m>::modulus() as $w) as u32)
+            }
+        }
+    };
+    ([$($g:tt)*] $m:ty, $w:ty, $t:ty, $($rest:ty),+) => {
+        impl_from!([$($g)*] $m, $w, $t);
+        impl_from!([$($g)*] $m, $w, $($rest),+);
+    };
+}
+
+macro_rules! impl_modint {
+    ([$($g:tt)*] $m:ty) => {
+        impl_from!([$($g)*] $m, u64, u8, u16, u32, u64, usize);
+        impl_from!([$($g)*] $m, u128, u128);
+        impl_from!([$($g)*] $m, i64, i8, i16, i32, i64, isize);
+        impl_from!([$($g)*] $m, i128, i128);
+
+        impl<$($g)*> $m {
+            pub fn val(self) -> u32 {
+                self.val
+            }
+
+            pub fn pow(self, n: u64) -> Self {
+                <Self as ModIntBase>::pow(self, n)
+            }
+
+            /// panics if `self` is not coprime to the modulus.
+            pub fn inv(self) -> Self {
+                <Self as Inv>::inv(self)
+            }
+        }
+
+        impl<$($g)*> Add for $m {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                let v = self.val as u64 + rhs.val as u64;
+                let m = <$m>::modulus() as u64;
+                Self::raw(if v >= m { v - m } else { v } as u32)
+            }
+        }
+
+        impl<$($g)*> Sub for $m {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                if self.val >= rhs.val {
+                    Self::raw(self.val - rhs.val)
+                } else {
+                    Self::raw((self.val as u64 + <$m>::modulus() as u64 - rhs.val as u64) as u32)
+                }
+            }
+        }
+
+        impl<$($g)*> Mul for $m {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                Self::raw((self.val as u64 * rhs.val as u64 % <$m>::modulus() as u64) as u32)
+            }
+        }
+
+        impl<$($g)*> Div for $m {
+            type Output = Self;
+            #[allow(clippy::suspicious_arithmetic_impl)]
+            fn div(self, rhs: Self) -> Self {
+                self * rhs.inv()
+            }
+        }
+
+        impl<$($g)*> Neg for $m {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self::zero() - self
+            }
+        }
+
+        impl<$($g)*> AddAssign for $m {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<$($g)*> SubAssign for $m {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<$($g)*> MulAssign for $m {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<$($g)*> DivAssign for $m {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<$($g)*> Sum for $m {
+            fn sum<It: Iterator<Item = Self>>(iter: It) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<$($g)*> Product for $m {
+            fn product<It: Iterator<Item = Self>>(iter: It) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * x)
+            }
+        }
+
+        impl<$($g)*> Zero for $m {
+            fn zero() -> Self {
+                Self::raw(0)
+            }
+        }
+
+        impl<$($g)*> One for $m {
+            fn one() -> Self {
+                Self::raw(1 % <$m>::modulus())
+            }
+        }
+
+        impl<$($g)*> CommutativeAdd for $m {}
+        impl<$($g)*> CommutativeMul for $m {}
+
+        impl<$($g)*> Inv for $m {
+            fn inv(self) -> Self {
+                Self::raw(inv_mod(self.val, <$m>::modulus()))
+            }
+        }
+
+        impl<$($g)*> Display for $m {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.val)
+            }
+        }
+
+        impl<$($g)*> Debug for $m {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.val)
+            }
+        }
+
+        /// accepts integers of any length, reduced modulo the modulus.
+        impl<$($g)*> FromStr for $m {
+            type Err = ParseModIntError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let (neg, digits) = match s.as_bytes().first() {
+                    Some(b'-') => (true, &s[1..]),
+                    Some(b'+') => (false, &s[1..]),
+                    _ => (false, s),
+                };
+                check_digits(digits)?;
+                let ten = Self::from(10u32);
+                let res = digits
+                    .bytes()
+                    .fold(Self::zero(), |acc, c| acc * ten + Self::from(c - b'0'));
+                Ok(if neg { -res } else { res })
+            }
+        }
+    };
+}
+
+impl_modint!([const M: u32] StaticModInt<M>);
+impl_modint!([I: ModulusId] DynamicModInt<I>);
+
+#[cfg(test)]
+mod test {
+    use std::ops::Add;
+
+    use group::Group;
+    use magma::{AddMagma, Commutative, MulMagma};
+    use semiring::{AddMul, Field};
+    use zero::Zero;
+
+    use crate::{DynamicModInt, ModInt1000000007, ModInt998244353, ModulusId, ParseModIntError};
+
+    type Mint = ModInt998244353;
+
+    #[test]
+    fn test_static() {
+        let a = Mint::new(1_000_000_000u64);
+        assert_eq!(a.val(), 1_755_647);
+        assert_eq!(Mint::new(-1).val(), 998244352);
+        assert_eq!(Mint::new(2).inv() * Mint::new(2), Mint::new(1));
+        assert_eq!(Mint::new(3) - Mint::new(5), Mint::new(-2));
+        assert_eq!(ModInt1000000007::new(2).pow(30).val(), 73741817);
+        assert_eq!(
+            (1..=10).map(Mint::new).product::<Mint>(),
+            Mint::new(3628800)
+        );
+        assert_eq!(
+            "123456789012345678901234567890".parse::<Mint>(),
+            Ok(Mint::new(123456789012345678901234567890u128))
+        );
+        assert_eq!("-3".parse::<Mint>(), Ok(-Mint::new(3)));
+        assert_eq!("".parse::<Mint>(), Err(ParseModIntError::Empty));
+        assert_eq!("-".parse::<Mint>(), Err(ParseModIntError::Empty));
+        assert_eq!("12a".parse::<Mint>(), Err(ParseModIntError::InvalidDigit));
+        assert_eq!(format!("{}", Mint::new(-1)), "998244352");
+    }
+
+    enum Mod6 {}
+    impl ModulusId for Mod6 {
+        fn modulus_cell() -> &'static std::sync::atomic::AtomicU32 {
+            static M: std::sync::atomic::AtomicU32 = std::sync::atomic::AtomicU32::new(6);
+            &M
+        }
+    }
+
+    #[test]
+    fn test_dynamic() {
+        type Z6 = DynamicModInt<Mod6>;
+        assert_eq!(Z6::new(-1).val(), 5);
+        assert_eq!(Z6::new(5).inv(), Z6::new(5));
+        assert_eq!(Z6::new(4) * Z6::new(3), Z6::zero());
+        Z6::set_modulus(10);
+        assert_eq!(Z6::new(25).val(), 5);
+    }
+
+    fn assert_group<G: Group>() {}
+    fn assert_commutative<G: Commutative>() {}
+    fn assert_weight<W: Clone + Add<Output = W> + Ord + Zero>() {}
+
+    #[test]
+    fn test_algebra() {
+        assert_group::<AddMagma<Mint>>();
+        assert_group::<MulMagma<Mint>>();
+        assert_commutative::<AddMagma<Mint>>();
+        assert_weight::<Mint>();
+        assert_eq!(AddMagma::<Mint>::inverse(Mint::new(1)), Mint::new(-1));
+        assert_eq!(
+            AddMul::<Mint>::div(Mint::new(1), Mint::new(2)),
+            Mint::new(499122177)
+        );
+    }
+}
@@ -1,4 +1,12 @@
+use std::ops::{Add, Mul};
+
+use bounded::Bounded;
 use magma::*;
+use one::One;
+use zero::Zero;
+
+#[doc(hidden)]
+pub use magma;
 
 pub trait Monoid
 where
@@ -7,54 +15,257 @@ where
     fn identity() -> Self::S;
 }
 
-macro_rules! impl_max_min {
+/// define a marker type with `Magma` and `Monoid` impls.
+/// the operation is written like a closure whose parameters may be patterns,
+/// and a trailing `commutative` also implements `Commutative`.
+/// ```
+/// use magma::Magma;
+/// use monoid::{define_monoid, Monoid};
+///
+/// define_monoid! {
+///     /// composition of x -> ax + b applying `lhs` first.
+///     pub Composite: (i64, i64),
+///     identity: (1, 0),
+///     operation: |(a, b), (c, d)| (a * c, b * c + d),
+/// }
+///
+/// define_monoid! {
+///     Sum: u64,
+///     identity: 0,
+///     operation: |a, b| a + b,
+///     commutative,
+/// }
+///
+/// assert_eq!(Composite::binary_operation((2, 1), (3, 4)), (6, 7));
+/// assert_eq!(Composite::identity(), (1, 0));
+/// assert_eq!(Sum::binary_operation(12, 18), 30);
+/// ```
+#[macro_export]
+macro_rules! define_monoid {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident: $t:ty,
+        identity: $identity:expr,
+        operation: |$lhs:pat_param, $rhs:pat_param| $op:expr,
+        commutative $(,)?
+    ) => {
+        $crate::define_monoid! {
+            $(#[$attr])*
+            $vis $name: $t,
+            identity: $identity,
+            operation: |$lhs, $rhs| $op,
+        }
+
+        impl $crate::magma::Commutative for $name {}
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis $name:ident: $t:ty,
+        identity: $identity:expr,
+        operation: |$lhs:pat_param, $rhs:pat_param| $op:expr $(,)?
+    ) => {
+        $(#[$attr])*
+        $vis struct $name;
+
+        impl $crate::magma::Magma for $name {
+            type S = $t;
+            fn binary_operation($lhs: Self::S, $rhs: Self::S) -> Self::S {
+                $op
+            }
+        }
+
+        impl $crate::Monoid for $name {
+            fn identity() -> Self::S {
+                $identity
+            }
+        }
+    };
+}
+
+impl<T: Bounded> Monoid for MaxMagma<T> {
+    fn identity() -> Self::S {
+        T::min_value()
+    }
+}
+
+impl<T: Bounded> Monoid for MinMagma<T> {
+    fn identity() -> Self::S {
+        T::max_value()
+    }
+}
+
+impl<T: Bounded> Monoid for ArgMin<T> {
+    fn identity() -> Self::S {
+        (T::max_value(), usize::MAX)
+    }
+}
+
+impl<T: Bounded> Monoid for ArgMax<T> {
+    fn identity() -> Self::S {
+        (T::min_value(), usize::MAX)
+    }
+}
+
+macro_rules! impl_bitwise {
     ($t:ty) => {
-        impl Monoid for MaxMagma<$t> {
+        impl Monoid for XorMagma<$t> {
+            fn identity() -> Self::S {
+                0
+            }
+        }
+
+        impl Monoid for AndMagma<$t> {
+            fn identity() -> Self::S {
+                !0
+            }
+        }
+
+        impl Monoid for OrMagma<$t> {
             fn identity() -> Self::S {
-                <$t>::MIN
+                0
             }
         }
 
-        impl Monoid for MinMagma<$t> {
+        impl Monoid for WrappingAddMagma<$t> {
             fn identity() -> Self::S {
-                <$t>::MAX
+                0
             }
         }
     };
 }
 
-impl_max_min!(usize);
-impl_max_min!(i8);
-impl_max_min!(i16);
-impl_max_min!(i32);
-impl_max_min!(i64);
-impl_max_min!(i128);
-impl_max_min!(u8);
-impl_max_min!(u16);
-impl_max_min!(u32);
-impl_max_min!(u64);
-impl_max_min!(u128);
+impl_bitwise!(usize);
+impl_bitwise!(i8);
+impl_bitwise!(i16);
+impl_bitwise!(i32);
+impl_bitwise!(i64);
+impl_bitwise!(i128);
+impl_bitwise!(u8);
+impl_bitwise!(u16);
+impl_bitwise!(u32);
+impl_bitwise!(u64);
+impl_bitwise!(u128);
+
+macro_rules! impl_unsigned {
+    ($t:ty) => {
+        impl Monoid for SaturatingAddMagma<$t> {
+            fn identity() -> Self::S {
+                0
+            }
+        }
+
+        impl Monoid for CheckedAddMagma<$t> {
+            fn identity() -> Self::S {
+                Some(0)
+            }
+        }
 
-macro_rules! impl_add {
-    ($t: ty, $zero:tt) => {
-        impl Monoid for AddMagma<$t> {
+        impl Monoid for GcdMagma<$t> {
             fn identity() -> Self::S {
-                $zero
+                0
+            }
+        }
+
+        impl Monoid for LcmMagma<$t> {
+            fn identity() -> Self::S {
+                1
             }
         }
     };
 }
 
-impl_add!(usize, 0);
-impl_add!(i8, 0);
-impl_add!(i16, 0);
-impl_add!(i32, 0);
-impl_add!(i64, 0);
-impl_add!(i128, 0);
-impl_add!(u8, 0);
-impl_add!(u16, 0);
-impl_add!(u32, 0);
-impl_add!(u64, 0);
-impl_add!(u128, 0);
-impl_add!(f32, 0.0);
-impl_add!(f64, 0.0);
+impl_unsigned!(usize);
+impl_unsigned!(u8);
+impl_unsigned!(u16);
+impl_unsigned!(u32);
+impl_unsigned!(u64);
+impl_unsigned!(u128);
+
+impl<T: Add<Output = T> + Zero> Monoid for AddMagma<T> {
+    fn identity() -> Self::S {
+        T::zero()
+    }
+}
+
+impl<T: Mul<Output = T> + One> Monoid for MulMagma<T> {
+    fn identity() -> Self::S {
+        T::one()
+    }
+}
+
+impl<M1: Monoid, M2: Monoid> Monoid for Pair<M1, M2> {
+    fn identity() -> Self::S {
+        (M1::identity(), M2::identity())
+    }
+}
+
+impl<M: Monoid> Monoid for Rev<M> {
+    fn identity() -> Self::S {
+        M::identity()
+    }
+}
+
+impl<Sg: Magma> Monoid for OptionMonoid<Sg> {
+    fn identity() -> Self::S {
+        None
+    }
+}
+
+impl<T: Clone + Add<Output = T> + Mul<Output = T> + Zero + One> Monoid for Affine<T> {
+    fn identity() -> Self::S {
+        (T::one(), T::zero())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use magma::{AddMagma, Affine, ArgMax, ArgMin, Magma, MaxMagma, OptionMonoid, Pair, Rev};
+
+    use crate::Monoid;
+
+    fn fold<M: Monoid>(v: &[M::S]) -> M::S
+    where
+        M::S: Clone,
+    {
+        v.iter()
+            .cloned()
+            .fold(M::identity(), |acc, x| M::binary_operation(acc, x))
+    }
+
+    /// string concatenation, a semigroup without an identity in `S`.
+    struct Concat;
+    impl Magma for Concat {
+        type S = String;
+        fn binary_operation(lhs: String, rhs: String) -> String {
+            lhs + &rhs
+        }
+    }
+
+    #[test]
+    fn test_combinators() {
+        let v = [(3, 1), (-2, 4), (5, 1)];
+        assert_eq!(fold::<Pair<AddMagma<i32>, MaxMagma<i32>>>(&v), (6, 4));
+
+        let s = ["a", "b", "c"].map(|x| Some(x.to_string()));
+        assert_eq!(fold::<OptionMonoid<Concat>>(&s), Some("abc".to_string()));
+        assert_eq!(
+            fold::<Rev<OptionMonoid<Concat>>>(&s),
+            Some("cba".to_string())
+        );
+        assert_eq!(fold::<OptionMonoid<Concat>>(&[]), None);
+
+        let w = [3, 1, 4, 1, 5, 9, 2, 6, 5]
+            .into_iter()
+            .enumerate()
+            .map(|(i, x)| (x, i))
+            .collect::<Vec<_>>();
+        assert_eq!(fold::<ArgMin<i32>>(&w), (1, 1));
+        assert_eq!(fold::<ArgMax<i32>>(&w), (9, 5));
+        assert_eq!(fold::<ArgMax<i32>>(&w[6..]), (6, 7));
+        assert_eq!(fold::<ArgMax<i32>>(&[(5, 3), (5, 2)]), (5, 2));
+
+        // x -> 2x + 1, then x -> 3x
+        assert_eq!(fold::<Affine<i64>>(&[(2, 1), (3, 0)]), (6, 3));
+        assert_eq!(fold::<Rev<Affine<i64>>>(&[(2, 1), (3, 0)]), (6, 1));
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+
+use prime::factorize;
+
+fn ext_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0.div_euclid(r1);
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// (g, x, y) with a x + b y = g = gcd(a, b) >= 0.
+/// ```
+/// use number_theory::ext_gcd;
+///
+/// let (g, x, y) = ext_gcd(12, -18);
+/// assert_eq!(g, 6);
+/// assert_eq!(12 * x - 18 * y, 6);
+/// ```
+pub fn ext_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = ext_gcd_i128(a as i128, b as i128);
+    (g as i64, x as i64, y as i64)
+}
+
+/// x in \[0, m) with a x = 1 (mod m). `m` need not be prime.
+/// ```
+/// use number_theory::inv_mod;
+///
+/// assert_eq!(inv_mod(5, 12), Some(5));
+/// assert_eq!(inv_mod(4, 12), None);
+/// ```
+pub fn inv_mod(a: i64, m: i64) -> Option<i64> {
+    assert!(m >= 1);
+    let (g, x, _) = ext_gcd(a.rem_euclid(m), m);
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(m))
+}
+
+/// (r, lcm) such that x = r (mod lcm) iff x = r_i (mod m_i) for all (r_i, m_i) in `congruences`.
+/// moduli need not be pairwise coprime. None if there is no solution.
+/// panics if lcm does not fit in i64.
+/// ```
+/// use number_theory::crt;
+///
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12)));
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+/// ```
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut r0, mut m0) = (0i128, 1i128);
+    for &(r1, m1) in congruences {
+        assert!(m1 >= 1);
+        let (r1, m1) = ((r1 as i128).rem_euclid(m1 as i128), m1 as i128);
+        let (g, p, _) = ext_gcd_i128(m0, m1);
+        if (r1 - r0) % g != 0 {
+            return None;
+        }
+        let u = m1 / g;
+        let t = ((r1 - r0) / g).rem_euclid(u) * p.rem_euclid(u) % u;
+        r0 += m0 * t;
+        m0 *= u;
+        assert!(m0 <= i64::MAX as i128, "lcm overflows i64");
+    }
+    Some((r0 as i64, m0 as i64))
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+/// a^n mod m.
+pub fn pow_mod(a: u64, n: u64, m: u64) -> u64 {
+    assert!(m >= 1);
+    let mut res = 1 % m;
+    let mut a = a % m;
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            res = mul_mod(res, a, m);
+        }
+        a = mul_mod(a, a, m);
+        n >>= 1;
+    }
+    res
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// smallest k >= 0 with x^k = y (mod m) by baby-step giant-step, in O(sqrt(m)).
+/// `x` and `m` need not be coprime.
+/// ```
+/// use number_theory::discrete_log;
+///
+/// assert_eq!(discrete_log(2, 3, 5), Some(3));
+/// assert_eq!(discrete_log(2, 0, 8), Some(3));
+/// assert_eq!(discrete_log(2, 3, 8), None);
+/// ```
+pub fn discrete_log(x: u64, y: u64, m: u64) -> Option<u64> {
+    assert!(m >= 1);
+    let (mut x, mut y, mut m) = (x % m, y % m, m);
+    let mut cur = 1 % m;
+    for k in 0..64 {
+        if cur == y {
+            return Some(k);
+        }
+        cur = mul_mod(cur, x, m);
+    }
+    // x^k = y (mod m) with k >= 64 reduces to a x^(k-shift) = y (mod m) with gcd(x, m) = 1.
+    let mut a = 1 % m;
+    let mut shift = 0;
+    loop {
+        let g = gcd(x, m);
+        if g == 1 {
+            break;
+        }
+        if y % g != 0 {
+            return None;
+        }
+        m /= g;
+        y /= g;
+        a = mul_mod(a % m, x / g, m);
+        x %= m;
+        shift += 1;
+    }
+    let n = (m as f64).sqrt().ceil() as u64 + 1;
+    let mut baby = HashMap::new();
+    let mut cur = y % m;
+    for j in 0..n {
+        baby.insert(cur, j);
+        cur = mul_mod(cur, x, m);
+    }
+    let giant = pow_mod(x, n, m);
+    let mut cur = a;
+    for i in 1..=n {
+        cur = mul_mod(cur, giant, m);
+        if let Some(&j) = baby.get(&cur) {
+            return Some(i * n - j + shift);
+        }
+    }
+    None
+}
+
+/// x with x^2 = a (mod p) for prime `p` by the Tonelli-Shanks algorithm.
+/// None if `a` is a quadratic nonresidue.
+/// ```
+/// use number_theory::sqrt_mod;
+///
+/// let x = sqrt_mod(5, 11).unwrap();
+/// assert_eq!(x * x % 11, 5);
+/// assert_eq!(sqrt_mod(2, 5), None);
+/// ```
+pub fn sqrt_mod(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    if a == 0 || p == 2 {
+        return Some(a);
+    }
+    if pow_mod(a, (p - 1) / 2, p) != 1 {
+        return None;
+    }
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let mut z = 2;
+    while pow_mod(z, (p - 1) / 2, p) == 1 {
+        z += 1;
+    }
+    let mut m = s;
+    let mut c = pow_mod(z, q, p);
+    let mut t = pow_mod(a, q, p);
+    let mut r = pow_mod(a, q.div_ceil(2), p);
+    while t != 1 {
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = mul_mod(t2, t2, p);
+            i += 1;
+        }
+        let b = pow_mod(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mul_mod(b, b, p);
+        t = mul_mod(t, c, p);
+        r = mul_mod(r, b, p);
+    }
+    Some(r)
+}
+
+/// smallest primitive root of prime `p`.
+/// ```
+/// use number_theory::primitive_root;
+///
+/// assert_eq!(primitive_root(998244353), 3);
+/// assert_eq!(primitive_root(1_000_000_007), 5);
+/// ```
+pub fn primitive_root(p: u64) -> u64 {
+    if p == 2 {
+        return 1;
+    }
+    let factors = factorize(p - 1);
+    (2..p)
+        .find(|&g| {
+            factors
+                .iter()
+                .all(|&(q, _)| pow_mod(g, (p - 1) / q, p) != 1)
+        })
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{crt, discrete_log, ext_gcd, inv_mod, pow_mod, primitive_root, sqrt_mod};
+
+    #[test]
+    fn test_gcd_crt() {
+        for a in -30..=30 {
+            for b in -30..=30 {
+                let (g, x, y) = ext_gcd(a, b);
+                assert_eq!(a * x + b * y, g);
+                let naive = (1..=60)
+                    .filter(|d| a % d == 0 && b % d == 0)
+                    .max()
+                    .filter(|_| a != 0 || b != 0);
+                assert_eq!(g, naive.unwrap_or(0));
+            }
+        }
+        for m in 1..30 {
+            for a in -30..30 {
+                let naive = (0..m).find(|x: &i64| (a * x).rem_euclid(m) == 1 % m);
+                assert_eq!(inv_mod(a, m), naive);
+            }
+        }
+        for m0 in 1..12 {
+            for m1 in 1..12 {
+                for r0 in 0..m0 {
+                    for r1 in 0..m1 {
+                        let naive = (0..m0 * m1).find(|x| x % m0 == r0 && x % m1 == r1);
+                        let res = crt(&[(r0, m0), (r1 - m1, m1)]);
+                        assert_eq!(res.map(|x| x.0), naive);
+                        if let Some((_, l)) = res {
+                            assert_eq!(
+                                l,
+                                (1..=m0 * m1).find(|l| l % m0 == 0 && l % m1 == 0).unwrap()
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        let m0 = 1_000_000_000_000_000_003;
+        let m1 = 7;
+        let (r, l) = crt(&[(m0 - 1, m0), (3, m1)]).unwrap();
+        assert_eq!(l, m0 * m1);
+        assert_eq!((r % m0, r % m1), (m0 - 1, 3));
+    }
+
+    #[test]
+    fn test_discrete_log() {
+        for m in 1..60 {
+            for x in 0..m {
+                for y in 0..m {
+                    let naive = (0..2 * m).find(|&k| pow_mod(x, k, m) == y);
+                    assert_eq!(discrete_log(x, y, m), naive);
+                }
+            }
+        }
+        let p = 998244353;
+        let y = pow_mod(3, 123456789, p);
+        assert_eq!(discrete_log(3, y, p), Some(123456789));
+        let m = 1 << 20;
+        assert_eq!(
+            discrete_log(3, pow_mod(3, 100000, m), m).map(|k| pow_mod(3, k, m)),
+            Some(pow_mod(3, 100000, m))
+        );
+    }
+
+    #[test]
+    fn test_sqrt_root() {
+        for p in [2, 3, 5, 7, 11, 13, 17, 97, 257] {
+            for a in 0..p {
+                let naive = (0..p).any(|x| x * x % p == a);
+                match sqrt_mod(a, p) {
+                    Some(x) => assert_eq!(x * x % p, a),
+                    None => assert!(!naive),
+                }
+            }
+            let g = primitive_root(p);
+            let order = (1..p).find(|&k| pow_mod(g, k, p) == 1).unwrap();
+            assert_eq!(order, p - 1);
+        }
+        let p = 1_000_000_000_000_000_003;
+        let x = sqrt_mod(pow_mod(123456789, 2, p), p).unwrap();
+        assert!(x == 123456789 || x == p - 123456789);
+    }
+}
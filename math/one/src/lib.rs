@@ -0,0 +1,69 @@
+use std::num::{Saturating, Wrapping};
+use std::ops::Mul;
+
+pub trait One: Mul<Self> + Sized {
+    fn one() -> Self;
+}
+
+/// multiplicative inverse. `inv` of zero is unspecified.
+pub trait Inv: One {
+    fn inv(self) -> Self;
+}
+
+macro_rules! impl_one {
+    ($t:ty, $v:expr) => {
+        impl One for $t {
+            fn one() -> Self {
+                $v
+            }
+        }
+    };
+}
+
+impl_one!(usize, 1);
+impl_one!(i8, 1);
+impl_one!(i16, 1);
+impl_one!(i32, 1);
+impl_one!(i64, 1);
+impl_one!(i128, 1);
+impl_one!(u8, 1);
+impl_one!(u16, 1);
+impl_one!(u32, 1);
+impl_one!(u64, 1);
+impl_one!(u128, 1);
+impl_one!(f32, 1.0);
+impl_one!(f64, 1.0);
+
+// `Saturating` and `Wrapping` select the overflow behavior of `*`.
+// saturating arithmetic is associative on unsigned types only.
+impl_one!(Saturating<usize>, Saturating(1));
+impl_one!(Saturating<u8>, Saturating(1));
+impl_one!(Saturating<u16>, Saturating(1));
+impl_one!(Saturating<u32>, Saturating(1));
+impl_one!(Saturating<u64>, Saturating(1));
+impl_one!(Saturating<u128>, Saturating(1));
+
+impl_one!(Wrapping<usize>, Wrapping(1));
+impl_one!(Wrapping<i8>, Wrapping(1));
+impl_one!(Wrapping<i16>, Wrapping(1));
+impl_one!(Wrapping<i32>, Wrapping(1));
+impl_one!(Wrapping<i64>, Wrapping(1));
+impl_one!(Wrapping<i128>, Wrapping(1));
+impl_one!(Wrapping<u8>, Wrapping(1));
+impl_one!(Wrapping<u16>, Wrapping(1));
+impl_one!(Wrapping<u32>, Wrapping(1));
+impl_one!(Wrapping<u64>, Wrapping(1));
+impl_one!(Wrapping<u128>, Wrapping(1));
+
+macro_rules! impl_inv {
+    ($t:ty) => {
+        impl Inv for $t {
+            fn inv(self) -> Self {
+                self.recip()
+            }
+        }
+    };
+}
+
+impl_inv!(f32);
+impl_inv!(f64);
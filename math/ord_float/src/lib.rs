@@ -0,0 +1,261 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use bounded::Bounded;
+use magma::{CommutativeAdd, CommutativeMul};
+use one::{Inv, One};
+use zero::Zero;
+
+/// # Ordered Float
+/// float with a total order, usable where `Ord` is required.
+///
+/// NaN is never stored: `new` and every arithmetic operation panic if the value is NaN,
+/// so the order is the usual one with -∞ (`MIN`) and +∞ (`MAX`) at both ends.
+/// 0.0 and -0.0 are equal.
+/// ```
+/// use graph_base::{DirectedGraph, GraphBase};
+/// use magma::{AddMagma, MaxMagma};
+/// use binary_indexed_tree::BinaryIndexedTree;
+/// use ord_float::TotalF64;
+/// use segment_tree::SegmentTree;
+/// use shortest_path::ShortestPath;
+///
+/// let mut g = DirectedGraph::new(3);
+/// g.add_weighted_edge(0, 1, &TotalF64::new(0.5));
+/// g.add_weighted_edge(1, 2, &TotalF64::new(0.25));
+/// g.add_weighted_edge(0, 2, &TotalF64::new(1.0));
+/// assert_eq!(g.dijkstra(0).dist[2], Some(TotalF64::new(0.75)));
+///
+/// let v = [1.5, -2.0, 0.25].map(TotalF64::new);
+/// let bit = BinaryIndexedTree::<AddMagma<TotalF64>>::from_vec(&v.to_vec());
+/// assert_eq!(bit.range_sum(1..3), TotalF64::new(-1.75));
+///
+/// let seg = SegmentTree::<MaxMagma<TotalF64>>::from_vec(&v);
+/// assert_eq!(seg.prod(1..), TotalF64::new(0.25));
+/// assert_eq!(seg.prod(..0), TotalF64::MIN);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct OrdFloat<T>(T);
+
+pub type TotalF32 = OrdFloat<f32>;
+pub type TotalF64 = OrdFloat<f64>;
+
+macro_rules! impl_ord_float {
+    ($t:ty) => {
+        impl OrdFloat<$t> {
+            /// -∞
+            pub const MIN: Self = OrdFloat(<$t>::NEG_INFINITY);
+            /// +∞
+            pub const MAX: Self = OrdFloat(<$t>::INFINITY);
+
+            /// `x` must not be NaN.
+            pub fn new(x: $t) -> Self {
+                assert!(!x.is_nan(), "OrdFloat must not be NaN");
+                OrdFloat(x)
+            }
+
+            pub fn get(self) -> $t {
+                self.0
+            }
+        }
+
+        impl PartialEq for OrdFloat<$t> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for OrdFloat<$t> {}
+
+        impl Ord for OrdFloat<$t> {
+            fn cmp(&self, other: &Self) -> Ordering {
+                self.0.partial_cmp(&other.0).unwrap()
+            }
+        }
+
+        impl PartialOrd for OrdFloat<$t> {
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Bounded for OrdFloat<$t> {
+            fn min_value() -> Self {
+                Self::MIN
+            }
+            fn max_value() -> Self {
+                Self::MAX
+            }
+        }
+
+        impl Hash for OrdFloat<$t> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                // 0.0 and -0.0 must have the same hash.
+                (self.0 + 0.0).to_bits().hash(state);
+            }
+        }
+
+        impl Add for OrdFloat<$t> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self::new(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for OrdFloat<$t> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self::new(self.0 - rhs.0)
+            }
+        }
+
+        impl Mul for OrdFloat<$t> {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                Self::new(self.0 * rhs.0)
+            }
+        }
+
+        impl Div for OrdFloat<$t> {
+            type Output = Self;
+            fn div(self, rhs: Self) -> Self {
+                Self::new(self.0 / rhs.0)
+            }
+        }
+
+        impl Neg for OrdFloat<$t> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                OrdFloat(-self.0)
+            }
+        }
+
+        impl AddAssign for OrdFloat<$t> {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for OrdFloat<$t> {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for OrdFloat<$t> {
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for OrdFloat<$t> {
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl Sum for OrdFloat<$t> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl Product for OrdFloat<$t> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::one(), |acc, x| acc * x)
+            }
+        }
+
+        impl Zero for OrdFloat<$t> {
+            fn zero() -> Self {
+                OrdFloat(0.0)
+            }
+        }
+
+        impl One for OrdFloat<$t> {
+            fn one() -> Self {
+                OrdFloat(1.0)
+            }
+        }
+
+        impl CommutativeAdd for OrdFloat<$t> {}
+        impl CommutativeMul for OrdFloat<$t> {}
+
+        impl Inv for OrdFloat<$t> {
+            fn inv(self) -> Self {
+                Self::new(self.0.recip())
+            }
+        }
+
+        impl Display for OrdFloat<$t> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Display::fmt(&self.0, f)
+            }
+        }
+
+        impl Debug for OrdFloat<$t> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                Debug::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+impl_ord_float!(f32);
+impl_ord_float!(f64);
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{TotalF32, TotalF64};
+
+    #[test]
+    fn test_order() {
+        let mut v = [
+            3.5,
+            -0.0,
+            f64::INFINITY,
+            -1e300,
+            0.0,
+            f64::NEG_INFINITY,
+            2.0,
+        ]
+        .map(TotalF64::new)
+        .to_vec();
+        v.sort();
+        assert_eq!(
+            v.iter().map(|x| x.get()).collect::<Vec<_>>(),
+            [f64::NEG_INFINITY, -1e300, 0.0, 0.0, 2.0, 3.5, f64::INFINITY]
+        );
+        assert_eq!(v.iter().max(), Some(&TotalF64::MAX));
+        assert_eq!(TotalF64::new(0.0), TotalF64::new(-0.0));
+        let set = [0.0, -0.0, 1.0]
+            .map(TotalF64::new)
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 2);
+        assert!(TotalF32::new(1.0) < TotalF32::new(1.5));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (TotalF64::new(1.5), TotalF64::new(-4.0));
+        assert_eq!(a + b, TotalF64::new(-2.5));
+        assert_eq!(a - b, TotalF64::new(5.5));
+        assert_eq!(a * b, TotalF64::new(-6.0));
+        assert_eq!(b / a * a, b);
+        assert_eq!(-a, TotalF64::new(-1.5));
+        assert_eq!([a, b, a].into_iter().sum::<TotalF64>(), TotalF64::new(-1.0));
+        assert_eq!(format!("{} {:?}", a, b), "1.5 -4.0");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nan() {
+        let _ = TotalF64::MAX - TotalF64::MAX;
+    }
+}
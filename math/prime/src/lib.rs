@@ -0,0 +1,358 @@
+/// # Linear Sieve
+/// smallest prime factor, Möbius function and Euler's totient up to n in O(n).
+/// ```
+/// use prime::LinearSieve;
+///
+/// let sieve = LinearSieve::new(100);
+/// assert_eq!(sieve.primes()[..5], [2, 3, 5, 7, 11]);
+/// assert_eq!(sieve.factorize(60), vec![(2, 2), (3, 1), (5, 1)]);
+/// assert_eq!(sieve.mobius(30), -1);
+/// assert_eq!(sieve.phi(36), 12);
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinearSieve {
+    n: usize,
+    spf: Vec<u32>,
+    primes: Vec<usize>,
+    mobius: Vec<i8>,
+    phi: Vec<u32>,
+}
+
+impl LinearSieve {
+    pub fn new(n: usize) -> LinearSieve {
+        let mut spf = vec![0; n + 1];
+        let mut primes = vec![];
+        let mut mobius = vec![0; n + 1];
+        let mut phi = vec![0; n + 1];
+        if n >= 1 {
+            mobius[1] = 1;
+            phi[1] = 1;
+        }
+        for i in 2..=n {
+            if spf[i] == 0 {
+                spf[i] = i as u32;
+                primes.push(i);
+                mobius[i] = -1;
+                phi[i] = i as u32 - 1;
+            }
+            for &p in &primes {
+                if p > spf[i] as usize || i * p > n {
+                    break;
+                }
+                spf[i * p] = p as u32;
+                if p == spf[i] as usize {
+                    mobius[i * p] = 0;
+                    phi[i * p] = phi[i] * p as u32;
+                } else {
+                    mobius[i * p] = -mobius[i];
+                    phi[i * p] = phi[i] * (p as u32 - 1);
+                }
+            }
+        }
+        LinearSieve {
+            n,
+            spf,
+            primes,
+            mobius,
+            phi,
+        }
+    }
+
+    pub fn is_prime(&self, x: usize) -> bool {
+        assert!(x <= self.n);
+        2 <= x && self.spf[x] as usize == x
+    }
+
+    /// primes up to n in increasing order.
+    pub fn primes(&self) -> &[usize] {
+        &self.primes
+    }
+
+    pub fn smallest_prime_factor(&self, x: usize) -> usize {
+        assert!(2 <= x && x <= self.n);
+        self.spf[x] as usize
+    }
+
+    /// (prime, exponent) in increasing order of prime.
+    pub fn factorize(&self, x: usize) -> Vec<(usize, u32)> {
+        assert!(1 <= x && x <= self.n);
+        let mut x = x;
+        let mut res: Vec<(usize, u32)> = vec![];
+        while x > 1 {
+            let p = self.spf[x] as usize;
+            match res.last_mut() {
+                Some(last) if last.0 == p => last.1 += 1,
+                _ => res.push((p, 1)),
+            }
+            x /= p;
+        }
+        res
+    }
+
+    pub fn mobius(&self, x: usize) -> i8 {
+        assert!(1 <= x && x <= self.n);
+        self.mobius[x]
+    }
+
+    pub fn phi(&self, x: usize) -> usize {
+        assert!(1 <= x && x <= self.n);
+        self.phi[x] as usize
+    }
+}
+
+fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    (a as u128 * b as u128 % m as u128) as u64
+}
+
+fn pow_mod(a: u64, n: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    let mut a = a % m;
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            res = mul_mod(res, a, m);
+        }
+        a = mul_mod(a, a, m);
+        n >>= 1;
+    }
+    res
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// deterministic Miller-Rabin test for 64-bit integers.
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    for p in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37] {
+        if n.is_multiple_of(p) {
+            return n == p;
+        }
+    }
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    [2, 325, 9375, 28178, 450775, 9780504, 1795265022]
+        .into_iter()
+        .all(|a| {
+            let mut x = pow_mod(a, d, n);
+            if x == 0 || x == 1 || x == n - 1 {
+                return true;
+            }
+            for _ in 1..s {
+                x = mul_mod(x, x, n);
+                if x == n - 1 {
+                    return true;
+                }
+            }
+            false
+        })
+}
+
+/// some nontrivial factor of composite `n` by Pollard's rho with Brent's cycle detection.
+fn pollard_rho(n: u64) -> u64 {
+    if n.is_multiple_of(2) {
+        return 2;
+    }
+    const BLOCK: u64 = 128;
+    for c in 1.. {
+        let f = |x: u64| ((mul_mod(x, x, n) as u128 + c as u128) % n as u128) as u64;
+        let mut y = 2;
+        let mut x = y;
+        let mut ys = y;
+        let mut q = 1;
+        let mut g = 1;
+        let mut r = 1;
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                for _ in 0..BLOCK.min(r - k) {
+                    y = f(y);
+                    q = mul_mod(q, x.abs_diff(y), n);
+                }
+                g = gcd(q, n);
+                k += BLOCK;
+            }
+            r *= 2;
+        }
+        if g == n {
+            loop {
+                ys = f(ys);
+                g = gcd(x.abs_diff(ys), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return g;
+        }
+    }
+    unreachable!()
+}
+
+/// (prime, exponent) in increasing order of prime.
+/// ```
+/// use prime::factorize;
+///
+/// assert_eq!(factorize(1), vec![]);
+/// assert_eq!(factorize(999999999989 * 6), vec![(2, 1), (3, 1), (999999999989, 1)]);
+/// ```
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    assert!(n >= 1);
+    let mut stack = vec![n];
+    let mut primes = vec![];
+    while let Some(x) = stack.pop() {
+        if x == 1 {
+            continue;
+        }
+        if is_prime(x) {
+            primes.push(x);
+            continue;
+        }
+        let d = pollard_rho(x);
+        stack.push(d);
+        stack.push(x / d);
+    }
+    primes.sort_unstable();
+    let mut res: Vec<(u64, u32)> = vec![];
+    for p in primes {
+        match res.last_mut() {
+            Some(last) if last.0 == p => last.1 += 1,
+            _ => res.push((p, 1)),
+        }
+    }
+    res
+}
+
+/// all divisors in increasing order, from the factorization returned by `factorize`.
+/// ```
+/// use prime::{divisors, factorize};
+///
+/// assert_eq!(divisors(&factorize(12)), vec![1, 2, 3, 4, 6, 12]);
+/// ```
+pub fn divisors(factors: &[(u64, u32)]) -> Vec<u64> {
+    let mut res = vec![1];
+    for &(p, e) in factors {
+        let len = res.len();
+        let mut pw = 1;
+        for _ in 0..e {
+            pw *= p;
+            for i in 0..len {
+                res.push(res[i] * pw);
+            }
+        }
+    }
+    res.sort_unstable();
+    res
+}
+
+/// primes in \[l, r), sieving by the primes up to sqrt(r).
+/// works in O((r - l) log log r + sqrt(r)), so r can be around 1e12 if r - l is small.
+/// ```
+/// use prime::primes_in_range;
+///
+/// assert_eq!(primes_in_range(1_000_000_000_000, 1_000_000_000_100), vec![1_000_000_000_039, 1_000_000_000_061, 1_000_000_000_063, 1_000_000_000_091]);
+/// ```
+pub fn primes_in_range(l: u64, r: u64) -> Vec<u64> {
+    if r <= l {
+        return vec![];
+    }
+    let mut sq = (r as f64).sqrt() as u64;
+    while sq * sq >= r && sq > 0 {
+        sq -= 1;
+    }
+    while (sq + 1) * (sq + 1) < r {
+        sq += 1;
+    }
+    let small = LinearSieve::new(sq as usize);
+    let mut is_prime = vec![true; (r - l) as usize];
+    for x in l..r.min(2) {
+        is_prime[(x - l) as usize] = false;
+    }
+    for &p in small.primes() {
+        let p = p as u64;
+        let start = (p * p).max(l.div_ceil(p) * p);
+        for x in (start..r).step_by(p as usize) {
+            is_prime[(x - l) as usize] = false;
+        }
+    }
+    (l..r).filter(|&x| is_prime[(x - l) as usize]).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{divisors, factorize, is_prime, primes_in_range, LinearSieve};
+
+    #[test]
+    fn test_sieve() {
+        let n = 10000;
+        let sieve = LinearSieve::new(n);
+        for x in 1..=n {
+            let naive = 2 <= x && (2..x).take_while(|d| d * d <= x).all(|d| x % d != 0);
+            assert_eq!(sieve.is_prime(x), naive);
+            assert_eq!(is_prime(x as u64), naive);
+            let f = sieve.factorize(x);
+            assert_eq!(f.iter().map(|&(p, e)| p.pow(e)).product::<usize>(), x);
+            let mu = if f.iter().any(|&(_, e)| e > 1) {
+                0
+            } else if f.len().is_multiple_of(2) {
+                1
+            } else {
+                -1
+            };
+            assert_eq!(sieve.mobius(x), mu);
+            let phi = (1..=x).filter(|&y| gcd(x, y) == 1).count();
+            if x <= 500 {
+                assert_eq!(sieve.phi(x), phi);
+            }
+        }
+        assert_eq!(sieve.primes().len(), 1229);
+    }
+
+    fn gcd(a: usize, b: usize) -> usize {
+        if b == 0 {
+            a
+        } else {
+            gcd(b, a % b)
+        }
+    }
+
+    #[test]
+    fn test_large() {
+        assert!(is_prime(998244353));
+        assert!(is_prime(1_000_000_007));
+        assert!(is_prime(18446744073709551557));
+        assert!(!is_prime(3215031751));
+        assert!(!is_prime(1_000_000_007 * 998244353));
+        let n = 1_000_000_007 * 998244353;
+        assert_eq!(factorize(n), vec![(998244353, 1), (1_000_000_007, 1)]);
+        let n = 2u64.pow(10) * 3u64.pow(4) * 4294967291;
+        assert_eq!(factorize(n), vec![(2, 10), (3, 4), (4294967291, 1)]);
+        assert_eq!(divisors(&factorize(n)).len(), 11 * 5 * 2);
+        assert_eq!(factorize(18446744073709551615).len(), 7);
+        // close to 2^64, where x^2 + c must not overflow.
+        let n = 4294967291 * 4294967279;
+        assert_eq!(factorize(n), vec![(4294967279, 1), (4294967291, 1)]);
+
+        let l = 999_999_990_000;
+        let r = 1_000_000_010_000;
+        let primes = primes_in_range(l, r);
+        assert_eq!(primes, (l..r).filter(|&x| is_prime(x)).collect::<Vec<_>>());
+        assert_eq!(
+            primes_in_range(0, 30),
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+    }
+}
@@ -0,0 +1,360 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+use magma::{CommutativeAdd, CommutativeMul};
+use one::{Inv, One};
+use zero::Zero;
+
+/// signed integer used for the numerator and the denominator.
+pub trait Integer:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Neg<Output = Self>
+{
+    fn div_euclid(self, rhs: Self) -> Self;
+
+    /// compare a / b and c / d for b, d > 0 without overflow.
+    fn cmp_fraction(a: Self, b: Self, c: Self, d: Self) -> Ordering;
+}
+
+impl Integer for i64 {
+    fn div_euclid(self, rhs: Self) -> Self {
+        i64::div_euclid(self, rhs)
+    }
+
+    fn cmp_fraction(a: Self, b: Self, c: Self, d: Self) -> Ordering {
+        (a as i128 * d as i128).cmp(&(c as i128 * b as i128))
+    }
+}
+
+impl Integer for i128 {
+    fn div_euclid(self, rhs: Self) -> Self {
+        i128::div_euclid(self, rhs)
+    }
+
+    fn cmp_fraction(a: Self, b: Self, c: Self, d: Self) -> Ordering {
+        // compare the continued fraction expansions.
+        let (q1, r1) = (a.div_euclid(b), a.rem_euclid(b));
+        let (q2, r2) = (c.div_euclid(d), c.rem_euclid(d));
+        if q1 != q2 {
+            return q1.cmp(&q2);
+        }
+        match (r1 == 0, r2 == 0) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => Self::cmp_fraction(d, r2, b, r1),
+        }
+    }
+}
+
+fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::zero() {
+        (a, b) = (b, a % b);
+    }
+    if a < T::zero() {
+        -a
+    } else {
+        a
+    }
+}
+
+/// # Rational
+/// exact fraction num / den, always kept with gcd(num, den) = 1 and den > 0.
+/// ```
+/// use rational::Rational;
+///
+/// type Q = Rational<i64>;
+/// let a = Q::new(6, -4);
+/// assert_eq!((a.num(), a.den()), (-3, 2));
+/// assert_eq!(a + Q::new(1, 2), Q::from(-1));
+/// assert_eq!((a.floor(), a.ceil()), (-2, -1));
+/// assert!(Q::new(1, 3) < Q::new(1, 2));
+/// assert_eq!(a.to_string(), "-3/2");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    num: T,
+    den: T,
+}
+
+impl<T: Integer> Rational<T> {
+    /// `den` must not be zero.
+    pub fn new(num: T, den: T) -> Self {
+        assert!(den != T::zero());
+        let g = gcd(num, den);
+        let (num, den) = (num / g, den / g);
+        if den < T::zero() {
+            Rational {
+                num: -num,
+                den: -den,
+            }
+        } else {
+            Rational { num, den }
+        }
+    }
+
+    pub fn num(&self) -> T {
+        self.num
+    }
+
+    pub fn den(&self) -> T {
+        self.den
+    }
+
+    /// largest integer not greater than self.
+    pub fn floor(&self) -> T {
+        self.num.div_euclid(self.den)
+    }
+
+    /// smallest integer not less than self.
+    pub fn ceil(&self) -> T {
+        -(-self.num).div_euclid(self.den)
+    }
+
+    pub fn abs(&self) -> Self {
+        if self.num < T::zero() {
+            -*self
+        } else {
+            *self
+        }
+    }
+}
+
+impl<T: Integer> From<T> for Rational<T> {
+    fn from(num: T) -> Self {
+        Rational { num, den: T::one() }
+    }
+}
+
+impl<T: Integer> Default for Rational<T> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: Integer> Ord for Rational<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        T::cmp_fraction(self.num, self.den, other.num, other.den)
+    }
+}
+
+impl<T: Integer> PartialOrd for Rational<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Integer> Add for Rational<T> {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let g = gcd(self.den, rhs.den);
+        Self::new(
+            self.num * (rhs.den / g) + rhs.num * (self.den / g),
+            self.den / g * rhs.den,
+        )
+    }
+}
+
+impl<T: Integer> Sub for Rational<T> {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self.add(-rhs)
+    }
+}
+
+impl<T: Integer> Mul for Rational<T> {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let g1 = gcd(self.num, rhs.den);
+        let g2 = gcd(rhs.num, self.den);
+        Self::new(
+            (self.num / g1) * (rhs.num / g2),
+            (self.den / g2) * (rhs.den / g1),
+        )
+    }
+}
+
+impl<T: Integer> Div for Rational<T> {
+    type Output = Self;
+    /// `rhs` must not be zero.
+    fn div(self, rhs: Self) -> Self {
+        self.mul(rhs.inv())
+    }
+}
+
+impl<T: Integer> Neg for Rational<T> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Rational {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+impl<T: Integer> AddAssign for Rational<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T: Integer> SubAssign for Rational<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T: Integer> MulAssign for Rational<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Integer> DivAssign for Rational<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<T: Integer> Sum for Rational<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<T: Integer> Product for Rational<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<T: Integer> Zero for Rational<T> {
+    fn zero() -> Self {
+        Rational {
+            num: T::zero(),
+            den: T::one(),
+        }
+    }
+}
+
+impl<T: Integer> One for Rational<T> {
+    fn one() -> Self {
+        Rational {
+            num: T::one(),
+            den: T::one(),
+        }
+    }
+}
+
+impl<T: Integer> CommutativeAdd for Rational<T> {}
+impl<T: Integer> CommutativeMul for Rational<T> {}
+
+impl<T: Integer> Inv for Rational<T> {
+    /// `self` must not be zero.
+    fn inv(self) -> Self {
+        Self::new(self.den, self.num)
+    }
+}
+
+impl<T: Integer> Display for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.den == T::one() {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+impl<T: Integer> Debug for Rational<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use group::Group;
+    use magma::{AddMagma, Magma, MulMagma};
+    use monoid::Monoid;
+    use semiring::{AddMul, Field, Ring, Semiring};
+
+    use crate::Rational;
+
+    type Q = Rational<i64>;
+
+    #[test]
+    fn test_arithmetic() {
+        let values = (-6..=6)
+            .flat_map(|n| (1..=6).map(move |d| Q::new(n, d)))
+            .collect::<Vec<_>>();
+        for &a in &values {
+            assert_eq!(a.floor(), (a.num() as f64 / a.den() as f64).floor() as i64);
+            assert_eq!(a.ceil(), (a.num() as f64 / a.den() as f64).ceil() as i64);
+            for &b in &values {
+                let (x, y) = (
+                    a.num() as f64 / a.den() as f64,
+                    b.num() as f64 / b.den() as f64,
+                );
+                let approx = |r: Q, v: f64| (r.num() as f64 / r.den() as f64 - v).abs() < 1e-9;
+                assert!(approx(a + b, x + y));
+                assert!(approx(a - b, x - y));
+                assert!(approx(a * b, x * y));
+                if b != Q::from(0) {
+                    assert!(approx(a / b, x / y));
+                }
+                assert_eq!(a.partial_cmp(&b), x.partial_cmp(&y));
+                assert_eq!(a == b, a.num() * b.den() == b.num() * a.den());
+            }
+        }
+        assert_eq!(Q::new(0, -5), Q::from(0));
+        assert_eq!(Q::new(0, -5).den(), 1);
+        assert_eq!(format!("{:?}", Q::new(4, 2)), "2");
+    }
+
+    #[test]
+    fn test_no_overflow() {
+        let big = i64::MAX;
+        assert!(Q::new(big - 1, big) < Q::new(big, big - 1));
+        assert!(Q::new(-big, big - 1) < Q::new(-(big - 1), big));
+        assert_eq!(Q::new(big, 3) * Q::new(3, big), Q::from(1));
+
+        type Q128 = Rational<i128>;
+        let big = i128::MAX;
+        assert!(Q128::new(big - 2, big - 1) < Q128::new(big - 1, big));
+        assert!(Q128::new(-big, 3) < Q128::new(-big + 1, 3));
+        assert_eq!(
+            Q128::new(big, 7).cmp(&Q128::new(big, 7)),
+            std::cmp::Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn test_algebra() {
+        let a = Q::new(3, 4);
+        let b = Q::new(-5, 6);
+        assert_eq!(AddMagma::<Q>::binary_operation(a, b), Q::new(-1, 12));
+        assert_eq!(AddMagma::<Q>::identity(), Q::from(0));
+        assert_eq!(AddMagma::<Q>::inverse(a), Q::new(-3, 4));
+        assert_eq!(MulMagma::<Q>::identity(), Q::from(1));
+        assert_eq!(MulMagma::<Q>::inverse(b), Q::new(-6, 5));
+        assert_eq!(AddMul::<Q>::sub(a, b), Q::new(19, 12));
+        assert_eq!(AddMul::<Q>::div(a, b), Q::new(-9, 10));
+        assert_eq!(AddMul::<Q>::mul(a, AddMul::<Q>::one()), a);
+    }
+}
@@ -0,0 +1,195 @@
+use std::marker::PhantomData;
+
+use group::Group;
+use magma::{AddMagma, Commutative, Magma, MaxMagma, MinMagma, MulMagma};
+use monoid::Monoid;
+
+/// semiring given by a commutative additive monoid and a multiplicative monoid.
+/// multiplication must distribute over addition, and zero must be absorbing.
+pub trait Semiring {
+    type S: Clone;
+    type Additive: Monoid<S = Self::S> + Commutative;
+    type Multiplicative: Monoid<S = Self::S>;
+
+    fn zero() -> Self::S {
+        Self::Additive::identity()
+    }
+
+    fn one() -> Self::S {
+        Self::Multiplicative::identity()
+    }
+
+    fn add(lhs: Self::S, rhs: Self::S) -> Self::S {
+        Self::Additive::binary_operation(lhs, rhs)
+    }
+
+    fn mul(lhs: Self::S, rhs: Self::S) -> Self::S {
+        Self::Multiplicative::binary_operation(lhs, rhs)
+    }
+}
+
+/// semiring whose additive monoid is a group.
+pub trait Ring: Semiring {
+    fn neg(x: Self::S) -> Self::S;
+
+    fn sub(lhs: Self::S, rhs: Self::S) -> Self::S {
+        Self::add(lhs, Self::neg(rhs))
+    }
+}
+
+/// commutative ring whose nonzero elements form a multiplicative group.
+pub trait Field: Ring {
+    /// `x` must not be zero.
+    fn inv(x: Self::S) -> Self::S;
+
+    fn div(lhs: Self::S, rhs: Self::S) -> Self::S {
+        Self::mul(lhs, Self::inv(rhs))
+    }
+}
+
+/// the usual (+, *).
+pub struct AddMul<T>(PhantomData<T>);
+
+impl<T> Semiring for AddMul<T>
+where
+    T: Clone,
+    AddMagma<T>: Monoid<S = T> + Commutative,
+    MulMagma<T>: Monoid<S = T>,
+{
+    type S = T;
+    type Additive = AddMagma<T>;
+    type Multiplicative = MulMagma<T>;
+}
+
+impl<T> Ring for AddMul<T>
+where
+    T: Clone,
+    AddMagma<T>: Group<S = T> + Commutative,
+    MulMagma<T>: Monoid<S = T>,
+{
+    fn neg(x: T) -> T {
+        AddMagma::<T>::inverse(x)
+    }
+}
+
+impl<T> Field for AddMul<T>
+where
+    T: Clone,
+    AddMagma<T>: Group<S = T> + Commutative,
+    MulMagma<T>: Group<S = T> + Commutative,
+{
+    fn inv(x: T) -> T {
+        MulMagma::<T>::inverse(x)
+    }
+}
+
+/// + with +∞ (`T::MAX`) as the absorbing element.
+pub struct MinPlusMulMagma<T>(PhantomData<T>);
+/// + with -∞ (`T::MIN`) as the absorbing element.
+pub struct MaxPlusMulMagma<T>(PhantomData<T>);
+
+/// tropical semiring (min, +). `T::MAX` is regarded as +∞.
+pub struct MinPlus<T>(PhantomData<T>);
+/// tropical semiring (max, +) over signed integers. `T::MIN` is regarded as -∞.
+pub struct MaxPlus<T>(PhantomData<T>);
+
+macro_rules! impl_min_plus {
+    ($t:ty) => {
+        impl Magma for MinPlusMulMagma<$t> {
+            type S = $t;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                if lhs == <$t>::MAX || rhs == <$t>::MAX {
+                    <$t>::MAX
+                } else {
+                    lhs + rhs
+                }
+            }
+        }
+
+        impl Monoid for MinPlusMulMagma<$t> {
+            fn identity() -> Self::S {
+                0
+            }
+        }
+
+        impl Commutative for MinPlusMulMagma<$t> {}
+
+        impl Semiring for MinPlus<$t> {
+            type S = $t;
+            type Additive = MinMagma<$t>;
+            type Multiplicative = MinPlusMulMagma<$t>;
+        }
+    };
+}
+
+// only for signed types, since -∞ (`T::MIN`) would coincide with the identity 0.
+macro_rules! impl_max_plus {
+    ($t:ty) => {
+        impl Magma for MaxPlusMulMagma<$t> {
+            type S = $t;
+            fn binary_operation(lhs: Self::S, rhs: Self::S) -> Self::S {
+                if lhs == <$t>::MIN || rhs == <$t>::MIN {
+                    <$t>::MIN
+                } else {
+                    lhs + rhs
+                }
+            }
+        }
+
+        impl Monoid for MaxPlusMulMagma<$t> {
+            fn identity() -> Self::S {
+                0
+            }
+        }
+
+        impl Commutative for MaxPlusMulMagma<$t> {}
+
+        impl Semiring for MaxPlus<$t> {
+            type S = $t;
+            type Additive = MaxMagma<$t>;
+            type Multiplicative = MaxPlusMulMagma<$t>;
+        }
+    };
+}
+
+impl_min_plus!(usize);
+impl_min_plus!(i8);
+impl_min_plus!(i16);
+impl_min_plus!(i32);
+impl_min_plus!(i64);
+impl_min_plus!(i128);
+impl_min_plus!(u8);
+impl_min_plus!(u16);
+impl_min_plus!(u32);
+impl_min_plus!(u64);
+impl_min_plus!(u128);
+
+impl_max_plus!(i8);
+impl_max_plus!(i16);
+impl_max_plus!(i32);
+impl_max_plus!(i64);
+impl_max_plus!(i128);
+
+#[cfg(test)]
+mod test {
+    use crate::{AddMul, Field, MaxPlus, MinPlus, Ring, Semiring};
+
+    fn dot<S: Semiring>(a: &[S::S], b: &[S::S]) -> S::S {
+        a.iter().zip(b).fold(S::zero(), |acc, (x, y)| {
+            S::add(acc, S::mul(x.clone(), y.clone()))
+        })
+    }
+
+    #[test]
+    fn test_semiring() {
+        assert_eq!(dot::<AddMul<i64>>(&[1, 2, 3], &[4, 5, 6]), 32);
+        assert_eq!(dot::<MinPlus<i64>>(&[1, 2, 3], &[4, 1, 6]), 3);
+        assert_eq!(
+            dot::<MinPlus<i64>>(&[i64::MAX, 2], &[-5, i64::MAX]),
+            i64::MAX
+        );
+        assert_eq!(dot::<MaxPlus<i32>>(&[1, 2, 3], &[4, 1, -6]), 5);
+        assert_eq!(AddMul::<i32>::sub(3, 5), -2);
+        assert_eq!(AddMul::<f64>::div(3.0, 4.0), 0.75);
+    }
+}
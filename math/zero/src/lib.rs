@@ -1,3 +1,4 @@
+use std::num::{Saturating, Wrapping};
 use std::ops::Add;
 
 pub trait Zero: Add<Self> + Sized {
@@ -27,3 +28,24 @@ impl_zero!(u64, 0);
 impl_zero!(u128, 0);
 impl_zero!(f32, 0.0);
 impl_zero!(f64, 0.0);
+
+// `Saturating` and `Wrapping` select the overflow behavior of `+`.
+// saturating arithmetic is associative on unsigned types only.
+impl_zero!(Saturating<usize>, Saturating(0));
+impl_zero!(Saturating<u8>, Saturating(0));
+impl_zero!(Saturating<u16>, Saturating(0));
+impl_zero!(Saturating<u32>, Saturating(0));
+impl_zero!(Saturating<u64>, Saturating(0));
+impl_zero!(Saturating<u128>, Saturating(0));
+
+impl_zero!(Wrapping<usize>, Wrapping(0));
+impl_zero!(Wrapping<i8>, Wrapping(0));
+impl_zero!(Wrapping<i16>, Wrapping(0));
+impl_zero!(Wrapping<i32>, Wrapping(0));
+impl_zero!(Wrapping<i64>, Wrapping(0));
+impl_zero!(Wrapping<i128>, Wrapping(0));
+impl_zero!(Wrapping<u8>, Wrapping(0));
+impl_zero!(Wrapping<u16>, Wrapping(0));
+impl_zero!(Wrapping<u32>, Wrapping(0));
+impl_zero!(Wrapping<u64>, Wrapping(0));
+impl_zero!(Wrapping<u128>, Wrapping(0));
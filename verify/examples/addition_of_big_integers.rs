@@ -0,0 +1,16 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/addition_of_big_integers
+
+use bigint::BigInt;
+use proconio::input;
+
+fn main() {
+    input! {
+        t: usize,
+        query: [(String, String); t],
+    }
+    for (a, b) in query {
+        let a: BigInt = a.parse().unwrap();
+        let b: BigInt = b.parse().unwrap();
+        println!("{}", a + b);
+    }
+}
@@ -0,0 +1,18 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/find_linear_recurrence
+
+use linear_recurrence::berlekamp_massey;
+use modint::ModInt998244353 as Mint;
+use proconio::input;
+use semiring::AddMul;
+
+fn main() {
+    input! {
+        n: usize,
+        a: [u32; n],
+    }
+    let a = a.into_iter().map(Mint::new).collect::<Vec<_>>();
+    let c = berlekamp_massey::<AddMul<Mint>>(&a);
+    println!("{}", c.len());
+    let ans = c.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    println!("{}", ans.join(" "));
+}
@@ -0,0 +1,23 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/binomial_coefficient_prime_mod
+
+use combinatorics::Combinatorics;
+use modint::ModInt;
+use proconio::input;
+
+fn main() {
+    input! {
+        t: usize,
+        m: u32,
+        query: [(u64, u64); t],
+    }
+    ModInt::set_modulus(m);
+    let n = 10_000_000.min(m as usize - 1);
+    let comb = Combinatorics::<ModInt>::new(n);
+    for (n, k) in query {
+        if comb.max_n() as u64 >= m as u64 - 1 {
+            println!("{}", comb.lucas(n, k));
+        } else {
+            println!("{}", comb.binom(n as usize, k as usize));
+        }
+    }
+}
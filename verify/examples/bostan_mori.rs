@@ -0,0 +1,17 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/kth_term_of_linearly_recurrent_sequence
+
+use linear_recurrence::nth_term_ntt;
+use modint::ModInt998244353 as Mint;
+use proconio::input;
+
+fn main() {
+    input! {
+        d: usize,
+        k: u64,
+        a: [u32; d],
+        c: [u32; d],
+    }
+    let a = a.into_iter().map(Mint::new).collect::<Vec<_>>();
+    let c = c.into_iter().map(Mint::new).collect::<Vec<_>>();
+    println!("{}", nth_term_ntt(&c, &a, k));
+}
@@ -0,0 +1,21 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/convolution_mod_1000000007
+
+use convolution::convolution_arbitrary;
+use modint::ModInt1000000007 as Mint;
+use proconio::input;
+
+fn main() {
+    input! {
+        n: usize,
+        m: usize,
+        a: [u32; n],
+        b: [u32; m],
+    }
+    let a = a.into_iter().map(Mint::new).collect::<Vec<_>>();
+    let b = b.into_iter().map(Mint::new).collect::<Vec<_>>();
+    let ans = convolution_arbitrary(&a, &b)
+        .into_iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>();
+    println!("{}", ans.join(" "));
+}
@@ -0,0 +1,17 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/discrete_logarithm_mod
+
+use number_theory::discrete_log;
+use proconio::input;
+
+fn main() {
+    input! {
+        t: usize,
+        query: [(u64, u64, u64); t],
+    }
+    for (x, y, m) in query {
+        match discrete_log(x, y, m) {
+            Some(k) => println!("{}", k),
+            None => println!("-1"),
+        }
+    }
+}
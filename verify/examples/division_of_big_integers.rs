@@ -0,0 +1,17 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/division_of_big_integers
+
+use bigint::BigUint;
+use proconio::input;
+
+fn main() {
+    input! {
+        t: usize,
+        query: [(String, String); t],
+    }
+    for (a, b) in query {
+        let a: BigUint = a.parse().unwrap();
+        let b: BigUint = b.parse().unwrap();
+        let (q, r) = a.div_rem(&b);
+        println!("{} {}", q, r);
+    }
+}
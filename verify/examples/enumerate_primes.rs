@@ -0,0 +1,22 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/enumerate_primes
+
+use prime::LinearSieve;
+use proconio::input;
+
+fn main() {
+    input! {
+        n: usize,
+        a: usize,
+        b: usize,
+    }
+    let sieve = LinearSieve::new(n);
+    let primes = sieve.primes();
+    let res = primes
+        .iter()
+        .skip(b)
+        .step_by(a)
+        .map(|p| p.to_string())
+        .collect::<Vec<_>>();
+    println!("{} {}", primes.len(), res.len());
+    println!("{}", res.join(" "));
+}
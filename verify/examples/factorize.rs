@@ -0,0 +1,20 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/factorize
+
+use prime::factorize;
+use proconio::input;
+
+fn main() {
+    input! {
+        q: usize,
+        a: [u64; q],
+    }
+    for a in a {
+        let mut res = vec![];
+        for (p, e) in factorize(a) {
+            for _ in 0..e {
+                res.push(p.to_string());
+            }
+        }
+        println!("{} {}", res.len(), res.join(" "));
+    }
+}
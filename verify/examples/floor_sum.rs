@@ -0,0 +1,14 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/sum_of_floor_of_linear
+
+use floor_sum::floor_sum;
+use proconio::input;
+
+fn main() {
+    input! {
+        t: usize,
+        query: [(i64, i64, i64, i64); t],
+    }
+    for (n, m, a, b) in query {
+        println!("{}", floor_sum(n, m, a, b));
+    }
+}
@@ -0,0 +1,15 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/exp_of_formal_power_series
+
+use fps::Fps998244353 as Fps;
+use modint::ModInt998244353 as Mint;
+use proconio::input;
+
+fn main() {
+    input! {
+        n: usize,
+        a: [u32; n],
+    }
+    let f = Fps::new(a.into_iter().map(Mint::new).collect());
+    let ans = f.exp(n).iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    println!("{}", ans.join(" "));
+}
@@ -0,0 +1,22 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/sqrt_of_formal_power_series
+
+use fps::Fps998244353 as Fps;
+use modint::ModInt998244353 as Mint;
+use proconio::input;
+
+fn main() {
+    input! {
+        n: usize,
+        a: [u32; n],
+    }
+    let f = Fps::new(a.into_iter().map(Mint::new).collect());
+    match f.sqrt(n) {
+        Some(g) => {
+            let ans = g.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+            println!("{}", ans.join(" "));
+        }
+        None => {
+            println!("-1");
+        }
+    }
+}
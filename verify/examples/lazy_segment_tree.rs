@@ -0,0 +1,56 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/range_affine_range_sum
+
+use action::define_action;
+use lazy_segment_tree::LazySegmentTree;
+use monoid::define_monoid;
+use proconio::input;
+
+const MOD: u64 = 998244353;
+
+define_monoid! {
+    Sum: (u64, u64),
+    identity: (0, 0),
+    operation: |(a, n), (b, m)| ((a + b) % MOD, n + m),
+    commutative,
+}
+
+define_monoid! {
+    Affine: (u64, u64),
+    identity: (1, 0),
+    operation: |(a, b), (c, d)| (a * c % MOD, (b * c + d) % MOD),
+}
+
+define_action! {
+    AffineSum,
+    monoid: Sum,
+    map: Affine,
+    act: |&(a, b), (s, n)| ((a * s + b * n) % MOD, n),
+}
+
+fn main() {
+    input! {
+        n: usize,
+        q: usize,
+        a: [u64; n],
+    }
+
+    let mut seg =
+        LazySegmentTree::<AffineSum>::from_vec(&a.into_iter().map(|x| (x, 1)).collect::<Vec<_>>());
+
+    for _ in 0..q {
+        input! {
+            t: usize,
+            l: usize,
+            r: usize,
+        }
+        if t == 0 {
+            input! {
+                b: u64,
+                c: u64,
+            }
+            seg.apply(l..r, (b, c));
+        } else {
+            println!("{}", seg.prod(l..r).0);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/matrix_det
+
+use matrix::Matrix;
+use modint::ModInt998244353 as Mint;
+use proconio::input;
+use semiring::AddMul;
+
+fn main() {
+    input! {
+        n: usize,
+        a: [[u32; n]; n],
+    }
+    let a = Matrix::<AddMul<Mint>>::from_vec(
+        a.into_iter()
+            .map(|row| row.into_iter().map(Mint::new).collect())
+            .collect(),
+    );
+    println!("{}", a.det());
+}
@@ -0,0 +1,54 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/point_add_rectangle_sum
+
+use binary_indexed_tree::CompressedBinaryIndexedTree2D;
+use magma::AddMagma;
+use proconio::input;
+
+enum Query {
+    Add(i64, i64, i64),
+    Sum(i64, i64, i64, i64),
+}
+
+fn main() {
+    input! {
+        n: usize,
+        q: usize,
+        init: [(i64, i64, i64); n],
+    }
+
+    let mut points = init.iter().map(|&(x, y, _)| (x, y)).collect::<Vec<_>>();
+    let mut queries = vec![];
+    for _ in 0..q {
+        input! {
+            t: usize,
+        }
+        if t == 0 {
+            input! {
+                x: i64,
+                y: i64,
+                w: i64,
+            }
+            points.push((x, y));
+            queries.push(Query::Add(x, y, w));
+        } else {
+            input! {
+                l: i64,
+                d: i64,
+                r: i64,
+                u: i64,
+            }
+            queries.push(Query::Sum(l, d, r, u));
+        }
+    }
+
+    let mut bit = CompressedBinaryIndexedTree2D::<AddMagma<i64>>::new(&points);
+    for (x, y, w) in init {
+        bit.add(x, y, &w);
+    }
+    for query in queries {
+        match query {
+            Query::Add(x, y, w) => bit.add(x, y, &w),
+            Query::Sum(l, d, r, u) => println!("{}", bit.range_sum(l..r, d..u)),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/pow_of_matrix
+
+use matrix::Matrix;
+use modint::ModInt998244353 as Mint;
+use proconio::input;
+use semiring::AddMul;
+
+fn main() {
+    input! {
+        n: usize,
+        k: u64,
+        a: [[u32; n]; n],
+    }
+    let a = Matrix::<AddMul<Mint>>::from_vec(
+        a.into_iter()
+            .map(|row| row.into_iter().map(Mint::new).collect())
+            .collect(),
+    );
+    let b = a.pow(k);
+    for i in 0..n {
+        let row = (0..n).map(|j| b[(i, j)].to_string()).collect::<Vec<_>>();
+        println!("{}", row.join(" "));
+    }
+}
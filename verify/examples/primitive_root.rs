@@ -0,0 +1,14 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/primitive_root
+
+use number_theory::primitive_root;
+use proconio::input;
+
+fn main() {
+    input! {
+        q: usize,
+        p: [u64; q],
+    }
+    for p in p {
+        println!("{}", primitive_root(p));
+    }
+}
@@ -0,0 +1,33 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/point_set_range_composite
+
+use monoid::define_monoid;
+use proconio::input;
+use segment_tree::SegmentTree;
+
+const MOD: u64 = 998244353;
+
+define_monoid! {
+    Composite: (u64, u64),
+    identity: (1, 0),
+    operation: |(a, b), (c, d)| (a * c % MOD, (b * c + d) % MOD),
+}
+
+fn main() {
+    input! {
+        n: usize,
+        q: usize,
+        f: [(u64, u64); n],
+        query: [(usize, usize, u64, u64); q],
+    }
+
+    let mut seg = SegmentTree::<Composite>::from_vec(&f);
+
+    for (t, a, b, c) in query {
+        if t == 0 {
+            seg.set(a, (b, c));
+        } else {
+            let (p, q) = seg.prod(a..(b as usize));
+            println!("{}", (p * c + q) % MOD);
+        }
+    }
+}
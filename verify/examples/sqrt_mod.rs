@@ -0,0 +1,17 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/sqrt_mod
+
+use number_theory::sqrt_mod;
+use proconio::input;
+
+fn main() {
+    input! {
+        t: usize,
+        query: [(u64, u64); t],
+    }
+    for (y, p) in query {
+        match sqrt_mod(y, p) {
+            Some(x) => println!("{}", x),
+            None => println!("-1"),
+        }
+    }
+}
@@ -0,0 +1,20 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/static_range_sum
+
+use magma::AddMagma;
+use proconio::input;
+use sparse_table::DisjointSparseTable;
+
+fn main() {
+    input! {
+        n: usize,
+        q: usize,
+        a: [u64; n],
+        query: [(usize, usize); q],
+    }
+
+    let st = DisjointSparseTable::<AddMagma<u64>>::from_vec(&a);
+
+    for (l, r) in query {
+        println!("{}", st.prod(l..r));
+    }
+}
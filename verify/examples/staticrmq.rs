@@ -0,0 +1,20 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/staticrmq
+
+use magma::MinMagma;
+use proconio::input;
+use sparse_table::SparseTable;
+
+fn main() {
+    input! {
+        n: usize,
+        q: usize,
+        a: [u32; n],
+        query: [(usize, usize); q],
+    }
+
+    let st = SparseTable::<MinMagma<u32>>::from_vec(&a);
+
+    for (l, r) in query {
+        println!("{}", st.prod(l..r));
+    }
+}
@@ -0,0 +1,31 @@
+// verification-helper: PROBLEM https://judge.yosupo.jp/problem/system_of_linear_equations
+
+use matrix::Matrix;
+use modint::ModInt998244353 as Mint;
+use proconio::input;
+use semiring::AddMul;
+
+fn main() {
+    input! {
+        n: usize,
+        m: usize,
+        a: [[u32; m]; n],
+        b: [u32; n],
+    }
+    let a = Matrix::<AddMul<Mint>>::from_vec(
+        a.into_iter()
+            .map(|row| row.into_iter().map(Mint::new).collect())
+            .collect(),
+    );
+    let b = b.into_iter().map(Mint::new).collect::<Vec<_>>();
+    match a.solve(&b) {
+        Some((x, kernel)) => {
+            println!("{}", kernel.len());
+            for v in std::iter::once(x).chain(kernel) {
+                let v = v.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+                println!("{}", v.join(" "));
+            }
+        }
+        None => println!("-1"),
+    }
+}